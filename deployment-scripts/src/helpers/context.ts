@@ -133,7 +133,7 @@ export default class Context {
         let { client, address: sender } = this.getTestUser('admin')
         let codeId = this.getCodeId(CONTRACT_MAP.DUTCH_AUCTION_LAUNCHPAD)
         let initMsg: DutchAuctionLaunchpadInstantiateMsg = {
-            accepted_denoms: [chainConfig.denom],
+            accepted_denoms: [{ denom: chainConfig.denom }],
             admin: sender,
             auction_creation_fee: { denom: chainConfig.denom, amount: '0' },
             max_auction_duration: 604800,
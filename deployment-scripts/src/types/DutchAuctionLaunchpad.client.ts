@@ -6,7 +6,7 @@
 
 import { CosmWasmClient, SigningCosmWasmClient, ExecuteResult } from "@cosmjs/cosmwasm-stargate";
 import { StdFee } from "@cosmjs/amino";
-import { Uint128, InstantiateMsg, Coin, ExecuteMsg, Decimal, Timestamp, Uint64, QueryMsg, Auction, ArrayOfTupleOfUint8AndAuction, Addr, Params } from "./DutchAuctionLaunchpad.types";
+import { Uint128, Decimal, InstantiateMsg, AcceptedDenom, Coin, ExecuteMsg, Timestamp, Uint64, HexBinary, PriceCurve, SettlementMode, TokenDelivery, Binary, VestingSchedule, QuoteDenom, Cw20ReceiveMsg, MerkleProof, QueryMsg, MigrateMsg, Addr, ArrayOfAddr, AssetInfo, AuctionStatus, Auction, Asset, ArrayOfTupleOfUint64AndAuction, BidderPosition, ArrayOfTupleOfAddrAndBidderPosition, ClaimableResponse, AuctionPhase, CurrentPriceResponse, Params, ProceedsResponse, DenomProceeds, SimulateBidResponse } from "./DutchAuctionLaunchpad.types";
 export interface DutchAuctionLaunchpadReadOnlyInterface {
   contractAddress: string;
   auctions: ({
@@ -15,13 +15,64 @@ export interface DutchAuctionLaunchpadReadOnlyInterface {
   }: {
     limit?: number;
     startAfter?: number;
-  }) => Promise<ArrayOfTupleOfUint8AndAuction>;
+  }) => Promise<ArrayOfTupleOfUint64AndAuction>;
   auction: ({
     auctionId
   }: {
     auctionId: number;
   }) => Promise<Auction>;
   params: () => Promise<Params>;
+  currentPrice: ({
+    auctionId
+  }: {
+    auctionId: number;
+  }) => Promise<CurrentPriceResponse>;
+  bids: ({
+    auctionId,
+    limit,
+    startAfter
+  }: {
+    auctionId: number;
+    limit?: number;
+    startAfter?: string;
+  }) => Promise<ArrayOfTupleOfAddrAndBidderPosition>;
+  bidderPosition: ({
+    auctionId,
+    bidder
+  }: {
+    auctionId: number;
+    bidder: string;
+  }) => Promise<BidderPosition>;
+  allowlist: ({
+    auctionId,
+    limit,
+    startAfter
+  }: {
+    auctionId: number;
+    limit?: number;
+    startAfter?: string;
+  }) => Promise<ArrayOfAddr>;
+  simulateBid: ({
+    amount,
+    atTime,
+    auctionId
+  }: {
+    amount: Coin;
+    atTime?: Timestamp;
+    auctionId: number;
+  }) => Promise<SimulateBidResponse>;
+  claimable: ({
+    auctionId,
+    bidder
+  }: {
+    auctionId: number;
+    bidder: string;
+  }) => Promise<ClaimableResponse>;
+  proceeds: ({
+    auctionId
+  }: {
+    auctionId: number;
+  }) => Promise<ProceedsResponse>;
 }
 export class DutchAuctionLaunchpadQueryClient implements DutchAuctionLaunchpadReadOnlyInterface {
   client: CosmWasmClient;
@@ -33,6 +84,13 @@ export class DutchAuctionLaunchpadQueryClient implements DutchAuctionLaunchpadRe
     this.auctions = this.auctions.bind(this);
     this.auction = this.auction.bind(this);
     this.params = this.params.bind(this);
+    this.currentPrice = this.currentPrice.bind(this);
+    this.bids = this.bids.bind(this);
+    this.bidderPosition = this.bidderPosition.bind(this);
+    this.allowlist = this.allowlist.bind(this);
+    this.simulateBid = this.simulateBid.bind(this);
+    this.claimable = this.claimable.bind(this);
+    this.proceeds = this.proceeds.bind(this);
   }
 
   auctions = async ({
@@ -41,7 +99,7 @@ export class DutchAuctionLaunchpadQueryClient implements DutchAuctionLaunchpadRe
   }: {
     limit?: number;
     startAfter?: number;
-  }): Promise<ArrayOfTupleOfUint8AndAuction> => {
+  }): Promise<ArrayOfTupleOfUint64AndAuction> => {
     return this.client.queryContractSmart(this.contractAddress, {
       auctions: {
         limit,
@@ -65,41 +123,185 @@ export class DutchAuctionLaunchpadQueryClient implements DutchAuctionLaunchpadRe
       params: {}
     });
   };
+  currentPrice = async ({
+    auctionId
+  }: {
+    auctionId: number;
+  }): Promise<CurrentPriceResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      current_price: {
+        auction_id: auctionId
+      }
+    });
+  };
+  bids = async ({
+    auctionId,
+    limit,
+    startAfter
+  }: {
+    auctionId: number;
+    limit?: number;
+    startAfter?: string;
+  }): Promise<ArrayOfTupleOfAddrAndBidderPosition> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      bids: {
+        auction_id: auctionId,
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
+  bidderPosition = async ({
+    auctionId,
+    bidder
+  }: {
+    auctionId: number;
+    bidder: string;
+  }): Promise<BidderPosition> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      bidder_position: {
+        auction_id: auctionId,
+        bidder
+      }
+    });
+  };
+  allowlist = async ({
+    auctionId,
+    limit,
+    startAfter
+  }: {
+    auctionId: number;
+    limit?: number;
+    startAfter?: string;
+  }): Promise<ArrayOfAddr> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      allowlist: {
+        auction_id: auctionId,
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
+  simulateBid = async ({
+    amount,
+    atTime,
+    auctionId
+  }: {
+    amount: Coin;
+    atTime?: Timestamp;
+    auctionId: number;
+  }): Promise<SimulateBidResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      simulate_bid: {
+        amount,
+        at_time: atTime,
+        auction_id: auctionId
+      }
+    });
+  };
+  claimable = async ({
+    auctionId,
+    bidder
+  }: {
+    auctionId: number;
+    bidder: string;
+  }): Promise<ClaimableResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      claimable: {
+        auction_id: auctionId,
+        bidder
+      }
+    });
+  };
+  proceeds = async ({
+    auctionId
+  }: {
+    auctionId: number;
+  }): Promise<ProceedsResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      proceeds: {
+        auction_id: auctionId
+      }
+    });
+  };
 }
 export interface DutchAuctionLaunchpadInterface extends DutchAuctionLaunchpadReadOnlyInterface {
   contractAddress: string;
   sender: string;
   createAuction: ({
+    allowlistOnly,
     endPrice,
     endTime,
     inDenom,
+    maxBidSize,
+    maxPerWallet,
+    merkleRoot,
+    minRaise,
     offeredAsset,
+    priceCurve,
+    proceedsVesting,
+    quoteDenoms,
+    settlementMode,
     startTime,
-    startingPrice
+    startingPrice,
+    stepInterval,
+    tokenDelivery
   }: {
+    allowlistOnly?: boolean;
     endPrice: Decimal;
     endTime: Timestamp;
     inDenom: string;
+    maxBidSize?: Uint128;
+    maxPerWallet?: Uint128;
+    merkleRoot?: HexBinary;
+    minRaise?: Uint128;
     offeredAsset: Coin;
+    priceCurve?: PriceCurve;
+    proceedsVesting?: VestingSchedule;
+    quoteDenoms?: QuoteDenom[];
+    settlementMode?: SettlementMode;
     startTime: Timestamp;
     startingPrice: Decimal;
+    stepInterval?: number;
+    tokenDelivery?: TokenDelivery;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  receive: ({
+    amount,
+    msg,
+    sender
+  }: {
+    amount: Uint128;
+    msg: Binary;
+    sender: string;
   }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  depositCreationFee: (fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  withdrawCreationFee: (fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
   bid: ({
-    auctionId
+    auctionId,
+    maxPrice,
+    merkleProof,
+    minAmountOut
   }: {
     auctionId: number;
+    maxPrice?: Decimal;
+    merkleProof?: MerkleProof;
+    minAmountOut?: Uint128;
   }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
   changeParams: ({
     acceptedDenoms,
     admin,
     auctionCreationFee,
+    cancellationFeeRefund,
     maxAuctionDuration,
+    maxVestingDuration,
     minSecondsUntilAuctionStart
   }: {
-    acceptedDenoms?: string[];
+    acceptedDenoms?: AcceptedDenom[];
     admin?: string;
     auctionCreationFee?: Coin;
+    cancellationFeeRefund?: Decimal;
     maxAuctionDuration?: number;
+    maxVestingDuration?: number;
     minSecondsUntilAuctionStart?: number;
   }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
   cancelAuction: ({
@@ -107,6 +309,50 @@ export interface DutchAuctionLaunchpadInterface extends DutchAuctionLaunchpadRea
   }: {
     auctionId: number;
   }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  claimProceeds: ({
+    auctionId
+  }: {
+    auctionId: number;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  finalizeAuction: ({
+    auctionId
+  }: {
+    auctionId: number;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  addToAllowlist: ({
+    addresses,
+    auctionId
+  }: {
+    addresses: string[];
+    auctionId: number;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  removeFromAllowlist: ({
+    addresses,
+    auctionId
+  }: {
+    addresses: string[];
+    auctionId: number;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  claimSettlement: ({
+    auctionId
+  }: {
+    auctionId: number;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  claimTokens: ({
+    auctionId
+  }: {
+    auctionId: number;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  claimVested: ({
+    auctionId
+  }: {
+    auctionId: number;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  claimRefund: ({
+    auctionId
+  }: {
+    auctionId: number;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
 }
 export class DutchAuctionLaunchpadClient extends DutchAuctionLaunchpadQueryClient implements DutchAuctionLaunchpadInterface {
   client: SigningCosmWasmClient;
@@ -119,45 +365,125 @@ export class DutchAuctionLaunchpadClient extends DutchAuctionLaunchpadQueryClien
     this.sender = sender;
     this.contractAddress = contractAddress;
     this.createAuction = this.createAuction.bind(this);
+    this.receive = this.receive.bind(this);
+    this.depositCreationFee = this.depositCreationFee.bind(this);
+    this.withdrawCreationFee = this.withdrawCreationFee.bind(this);
     this.bid = this.bid.bind(this);
     this.changeParams = this.changeParams.bind(this);
     this.cancelAuction = this.cancelAuction.bind(this);
+    this.claimProceeds = this.claimProceeds.bind(this);
+    this.finalizeAuction = this.finalizeAuction.bind(this);
+    this.addToAllowlist = this.addToAllowlist.bind(this);
+    this.removeFromAllowlist = this.removeFromAllowlist.bind(this);
+    this.claimSettlement = this.claimSettlement.bind(this);
+    this.claimTokens = this.claimTokens.bind(this);
+    this.claimVested = this.claimVested.bind(this);
+    this.claimRefund = this.claimRefund.bind(this);
   }
 
   createAuction = async ({
+    allowlistOnly,
     endPrice,
     endTime,
     inDenom,
+    maxBidSize,
+    maxPerWallet,
+    merkleRoot,
+    minRaise,
     offeredAsset,
+    priceCurve,
+    proceedsVesting,
+    quoteDenoms,
+    settlementMode,
     startTime,
-    startingPrice
+    startingPrice,
+    stepInterval,
+    tokenDelivery
   }: {
+    allowlistOnly?: boolean;
     endPrice: Decimal;
     endTime: Timestamp;
     inDenom: string;
+    maxBidSize?: Uint128;
+    maxPerWallet?: Uint128;
+    merkleRoot?: HexBinary;
+    minRaise?: Uint128;
     offeredAsset: Coin;
+    priceCurve?: PriceCurve;
+    proceedsVesting?: VestingSchedule;
+    quoteDenoms?: QuoteDenom[];
+    settlementMode?: SettlementMode;
     startTime: Timestamp;
     startingPrice: Decimal;
+    stepInterval?: number;
+    tokenDelivery?: TokenDelivery;
   }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
       create_auction: {
+        allowlist_only: allowlistOnly,
         end_price: endPrice,
         end_time: endTime,
         in_denom: inDenom,
+        max_bid_size: maxBidSize,
+        max_per_wallet: maxPerWallet,
+        merkle_root: merkleRoot,
+        min_raise: minRaise,
         offered_asset: offeredAsset,
+        price_curve: priceCurve,
+        proceeds_vesting: proceedsVesting,
+        quote_denoms: quoteDenoms,
+        settlement_mode: settlementMode,
         start_time: startTime,
-        starting_price: startingPrice
+        starting_price: startingPrice,
+        step_interval: stepInterval,
+        token_delivery: tokenDelivery
       }
     }, fee, memo, _funds);
   };
+  receive = async ({
+    amount,
+    msg,
+    sender
+  }: {
+    amount: Uint128;
+    msg: Binary;
+    sender: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      receive: {
+        amount,
+        msg,
+        sender
+      }
+    }, fee, memo, _funds);
+  };
+  depositCreationFee = async (fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      deposit_creation_fee: {}
+    }, fee, memo, _funds);
+  };
+  withdrawCreationFee = async (fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      withdraw_creation_fee: {}
+    }, fee, memo, _funds);
+  };
   bid = async ({
-    auctionId
+    auctionId,
+    maxPrice,
+    merkleProof,
+    minAmountOut
   }: {
     auctionId: number;
+    maxPrice?: Decimal;
+    merkleProof?: MerkleProof;
+    minAmountOut?: Uint128;
   }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
       bid: {
-        auction_id: auctionId
+        auction_id: auctionId,
+        max_price: maxPrice,
+        merkle_proof: merkleProof,
+        min_amount_out: minAmountOut
       }
     }, fee, memo, _funds);
   };
@@ -165,13 +491,17 @@ export class DutchAuctionLaunchpadClient extends DutchAuctionLaunchpadQueryClien
     acceptedDenoms,
     admin,
     auctionCreationFee,
+    cancellationFeeRefund,
     maxAuctionDuration,
+    maxVestingDuration,
     minSecondsUntilAuctionStart
   }: {
-    acceptedDenoms?: string[];
+    acceptedDenoms?: AcceptedDenom[];
     admin?: string;
     auctionCreationFee?: Coin;
+    cancellationFeeRefund?: Decimal;
     maxAuctionDuration?: number;
+    maxVestingDuration?: number;
     minSecondsUntilAuctionStart?: number;
   }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
@@ -179,7 +509,9 @@ export class DutchAuctionLaunchpadClient extends DutchAuctionLaunchpadQueryClien
         accepted_denoms: acceptedDenoms,
         admin,
         auction_creation_fee: auctionCreationFee,
+        cancellation_fee_refund: cancellationFeeRefund,
         max_auction_duration: maxAuctionDuration,
+        max_vesting_duration: maxVestingDuration,
         min_seconds_until_auction_start: minSecondsUntilAuctionStart
       }
     }, fee, memo, _funds);
@@ -195,4 +527,98 @@ export class DutchAuctionLaunchpadClient extends DutchAuctionLaunchpadQueryClien
       }
     }, fee, memo, _funds);
   };
+  claimProceeds = async ({
+    auctionId
+  }: {
+    auctionId: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      claim_proceeds: {
+        auction_id: auctionId
+      }
+    }, fee, memo, _funds);
+  };
+  finalizeAuction = async ({
+    auctionId
+  }: {
+    auctionId: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      finalize_auction: {
+        auction_id: auctionId
+      }
+    }, fee, memo, _funds);
+  };
+  addToAllowlist = async ({
+    addresses,
+    auctionId
+  }: {
+    addresses: string[];
+    auctionId: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      add_to_allowlist: {
+        addresses,
+        auction_id: auctionId
+      }
+    }, fee, memo, _funds);
+  };
+  removeFromAllowlist = async ({
+    addresses,
+    auctionId
+  }: {
+    addresses: string[];
+    auctionId: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      remove_from_allowlist: {
+        addresses,
+        auction_id: auctionId
+      }
+    }, fee, memo, _funds);
+  };
+  claimSettlement = async ({
+    auctionId
+  }: {
+    auctionId: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      claim_settlement: {
+        auction_id: auctionId
+      }
+    }, fee, memo, _funds);
+  };
+  claimTokens = async ({
+    auctionId
+  }: {
+    auctionId: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      claim_tokens: {
+        auction_id: auctionId
+      }
+    }, fee, memo, _funds);
+  };
+  claimVested = async ({
+    auctionId
+  }: {
+    auctionId: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      claim_vested: {
+        auction_id: auctionId
+      }
+    }, fee, memo, _funds);
+  };
+  claimRefund = async ({
+    auctionId
+  }: {
+    auctionId: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      claim_refund: {
+        auction_id: auctionId
+      }
+    }, fee, memo, _funds);
+  };
 }
\ No newline at end of file
@@ -0,0 +1,67 @@
+use std::fmt;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{to_json_binary, Addr, BankMsg, Coin, CosmosMsg, StdResult, Uint128, WasmMsg};
+use cw20::Cw20ExecuteMsg;
+
+/// Kind of token an auction deals in.
+#[cw_serde]
+pub enum AssetInfo {
+    Native { denom: String },
+    Cw20 { contract_addr: Addr },
+}
+
+#[cw_serde]
+pub struct Asset {
+    pub info: AssetInfo,
+    pub amount: Uint128,
+}
+
+impl AssetInfo {
+    /// Message sending `amount` of this token to `recipient`.
+    pub fn transfer_msg<T>(
+        &self,
+        recipient: impl Into<String>,
+        amount: Uint128,
+    ) -> StdResult<CosmosMsg<T>> {
+        let msg = match self {
+            AssetInfo::Native { denom } => BankMsg::Send {
+                to_address: recipient.into(),
+                amount: vec![Coin {
+                    denom: denom.clone(),
+                    amount,
+                }],
+            }
+            .into(),
+            AssetInfo::Cw20 { contract_addr } => WasmMsg::Execute {
+                contract_addr: contract_addr.to_string(),
+                msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: recipient.into(),
+                    amount,
+                })?,
+                funds: vec![],
+            }
+            .into(),
+        };
+        Ok(msg)
+    }
+}
+
+/// Native denom or CW20 contract address, as used in attributes.
+impl fmt::Display for AssetInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AssetInfo::Native { denom } => write!(f, "{denom}"),
+            AssetInfo::Cw20 { contract_addr } => write!(f, "{contract_addr}"),
+        }
+    }
+}
+
+impl From<Coin> for Asset {
+    fn from(coin: Coin) -> Self {
+        Asset {
+            info: AssetInfo::Native { denom: coin.denom },
+            amount: coin.amount,
+        }
+    }
+}
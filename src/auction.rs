@@ -1,42 +1,197 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Coin, Decimal, Timestamp, Uint128};
+use cosmwasm_std::{Coin, Decimal, HexBinary, Timestamp, Uint128};
 
-use crate::{state::Params, ContractError};
+use crate::{
+    asset::{Asset, AssetInfo},
+    price_curve::PriceCurve,
+    state::{Params, Settlement},
+    vesting::VestingSchedule,
+    ContractError,
+};
+
+#[cw_serde]
+pub enum AuctionStatus {
+    Open,
+    SoldOut,
+    Finalized,
+    Cancelled,
+    /// Ended below its minimum raise, bidders are refunded and the creator gets
+    /// the offered asset back
+    Failed,
+}
+
+#[cw_serde]
+pub enum AuctionPhase {
+    Pending,
+    Active,
+    Ended,
+    SoldOut,
+}
+
+/// How bidders are charged for the tokens they buy.
+#[cw_serde]
+#[derive(Default)]
+pub enum SettlementMode {
+    /// Each bid is filled at the price of its block
+    #[default]
+    PayAsBid,
+    /// Bids are committed during the auction and everyone buys at the final
+    /// clearing price once the auction sells out or ends
+    UniformPrice,
+}
+
+/// When bidders receive the tokens they bought.
+#[cw_serde]
+#[derive(Default)]
+pub enum TokenDelivery {
+    /// Tokens are sent with the bid, or at settlement for uniform price auctions
+    #[default]
+    Instant,
+    /// Tokens are held until `claim_start`, or the auction end if unset
+    Deferred { claim_start: Option<Timestamp> },
+    /// Tokens unlock linearly after the auction end
+    Vesting(VestingSchedule),
+}
+
+/// Additional native denom bids may be paid in.
+#[cw_serde]
+pub struct QuoteDenom {
+    pub denom: String,
+    /// Value of one unit of `denom` in units of the auction's `in_denom`
+    pub rate: Decimal,
+}
+
+/// Result of filling a bid at a given price.
+#[cw_serde]
+pub struct BidOutcome {
+    pub price: Decimal,
+    pub acquired_amount: Uint128,
+    pub cost: Uint128,
+    pub refund_amount: Uint128,
+}
 
 #[cw_serde]
 pub struct Auction {
     pub creator: String,
-    pub offered_asset: Coin,
+    pub offered_asset: Asset,
     pub in_denom: String,
+    /// Token named by `in_denom`, a CW20 when it is a contract address
+    pub in_asset: AssetInfo,
     pub starting_price: Decimal,
     pub end_price: Decimal,
     pub start_time: Timestamp,
     pub end_time: Timestamp,
     pub remaining_amount: Uint128,
+    pub total_raised: Uint128,
+    pub total_claimed: Uint128,
+    pub status: AuctionStatus,
+    pub held_creation_fee: Option<Coin>,
+    pub price_curve: PriceCurve,
+    /// When set, the price only changes once every `step_interval` seconds
+    pub step_interval: Option<u64>,
+    pub settlement_mode: SettlementMode,
+    /// Payments committed to a uniform price auction
+    pub total_committed: Uint128,
+    /// Price every bidder of a uniform price auction pays, set at finalization
+    pub clearing_price: Option<Decimal>,
+    /// Most offered tokens a single wallet may buy
+    pub max_per_wallet: Option<Uint128>,
+    /// Largest payment accepted in a single bid
+    pub max_bid_size: Option<Uint128>,
+    /// Only addresses on the auction's allowlist may bid
+    pub allowlist_only: bool,
+    /// Root of a merkle tree of `(address, allocation)` leaves bidders must prove
+    /// membership of
+    pub merkle_root: Option<HexBinary>,
+    /// Proceeds the auction must raise, otherwise it fails and bidders are
+    /// refunded. Tokens and proceeds stay in escrow until finalization.
+    pub min_raise: Option<Uint128>,
+    pub token_delivery: TokenDelivery,
+    /// Releases the creator's proceeds gradually after the auction end
+    pub proceeds_vesting: Option<VestingSchedule>,
+    /// Other denoms accepted at a fixed rate, prices stay quoted in `in_denom`
+    pub quote_denoms: Vec<QuoteDenom>,
+    /// Offered tokens are a token factory denom of the contract, minted as
+    /// they are sold instead of escrowed up front
+    pub mint_offered: bool,
+}
+
+impl BidOutcome {
+    pub fn check_slippage(
+        &self,
+        max_price: Option<Decimal>,
+        min_amount_out: Option<Uint128>,
+    ) -> Result<(), ContractError> {
+        if let Some(max_price) = max_price {
+            if self.price > max_price {
+                return Err(ContractError::PriceAboveMaxPrice {
+                    price: self.price,
+                    max_price,
+                });
+            }
+        }
+        if let Some(min_amount_out) = min_amount_out {
+            if self.acquired_amount < min_amount_out {
+                return Err(ContractError::AmountBelowMinAmountOut {
+                    acquired_amount: self.acquired_amount,
+                    min_amount_out,
+                });
+            }
+        }
+        Ok(())
+    }
 }
 
 impl Auction {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         creator: String,
-        offered_asset: Coin,
+        offered_asset: Asset,
         in_denom: String,
         starting_price: Decimal,
         end_price: Decimal,
         start_time: Timestamp,
         end_time: Timestamp,
+        price_curve: PriceCurve,
     ) -> Self {
         Auction {
             creator,
             offered_asset: offered_asset.clone(),
+            in_asset: AssetInfo::Native {
+                denom: in_denom.clone(),
+            },
             in_denom,
             starting_price,
             end_price,
             start_time,
             end_time,
             remaining_amount: offered_asset.amount,
+            total_raised: Uint128::zero(),
+            total_claimed: Uint128::zero(),
+            status: AuctionStatus::Open,
+            held_creation_fee: None,
+            price_curve,
+            step_interval: None,
+            settlement_mode: SettlementMode::PayAsBid,
+            total_committed: Uint128::zero(),
+            clearing_price: None,
+            max_per_wallet: None,
+            max_bid_size: None,
+            allowlist_only: false,
+            merkle_root: None,
+            min_raise: None,
+            token_delivery: TokenDelivery::Instant,
+            proceeds_vesting: None,
+            quote_denoms: vec![],
+            mint_offered: false,
         }
     }
     pub fn validate(&self, now: Timestamp, params: Params) -> Result<(), ContractError> {
+        // A uniform price auction divides by the supply to settle
+        if self.offered_asset.amount.is_zero() {
+            return Err(ContractError::ZeroOfferedAmount {});
+        }
+
         // Price validation
         if self.starting_price < self.end_price {
             return Err(ContractError::EndPriceHigherThanStartingPrice {});
@@ -50,6 +205,21 @@ impl Auction {
             });
         }
 
+        // Bidding denom must be whitelisted
+        let accepted_denom = params.accepted_denom(&self.in_denom).ok_or_else(|| {
+            ContractError::InDenomNotAccepted {
+                denom: self.in_denom.clone(),
+            }
+        })?;
+        if let Some(min_starting_price) = accepted_denom.min_starting_price {
+            if self.starting_price < min_starting_price {
+                return Err(ContractError::StartingPriceTooLow {
+                    starting_price: self.starting_price,
+                    min_starting_price,
+                });
+            }
+        }
+
         // // Denomination validation
         // if self.offered_asset.denom == self.in_denom {
         //     return Err(ContractError::SameDenomination {
@@ -86,6 +256,96 @@ impl Auction {
             });
         }
 
+        self.price_curve
+            .validate(self.starting_price, self.end_price, duration)?;
+
+        // Purchase caps
+        if self.max_per_wallet.is_some_and(|cap| cap.is_zero())
+            || self.max_bid_size.is_some_and(|cap| cap.is_zero())
+        {
+            return Err(ContractError::InvalidPurchaseCap {});
+        }
+        if self.min_raise.is_some_and(|min_raise| min_raise.is_zero()) {
+            return Err(ContractError::InvalidMinRaise {});
+        }
+        // Bought tokens can only be locked for so long after the end
+        let token_lock = match &self.token_delivery {
+            TokenDelivery::Instant | TokenDelivery::Deferred { claim_start: None } => 0,
+            TokenDelivery::Deferred {
+                claim_start: Some(claim_start),
+            } => {
+                if *claim_start < self.end_time {
+                    return Err(ContractError::InvalidClaimStart {});
+                }
+                claim_start.seconds() - self.end_time.seconds()
+            }
+            TokenDelivery::Vesting(schedule) => {
+                schedule.validate()?;
+                schedule.lock_duration()
+            }
+        };
+        if token_lock > params.max_vesting_duration {
+            return Err(ContractError::VestingTooLong {
+                duration: token_lock,
+                max_duration: params.max_vesting_duration,
+            });
+        }
+        if let Some(schedule) = &self.proceeds_vesting {
+            schedule.validate()?;
+            if schedule.lock_duration() > params.max_vesting_duration {
+                return Err(ContractError::VestingTooLong {
+                    duration: schedule.lock_duration(),
+                    max_duration: params.max_vesting_duration,
+                });
+            }
+        }
+        // Tokens bought in a uniform price auction are only known at settlement
+        if (self.max_per_wallet.is_some() || self.merkle_root.is_some())
+            && self.settlement_mode == SettlementMode::UniformPrice
+        {
+            return Err(ContractError::WalletCapRequiresPayAsBid {});
+        }
+        if self
+            .merkle_root
+            .as_ref()
+            .is_some_and(|root| root.len() != 32)
+        {
+            return Err(ContractError::InvalidMerkleRoot {});
+        }
+
+        // Refunds and settlements are only tracked in `in_denom`
+        if !self.quote_denoms.is_empty()
+            && (self.settlement_mode == SettlementMode::UniformPrice || self.min_raise.is_some())
+        {
+            return Err(ContractError::QuoteDenomsRequirePayAsBid {});
+        }
+        for (index, quote) in self.quote_denoms.iter().enumerate() {
+            if quote.rate.is_zero()
+                || quote.denom == self.in_denom
+                || params.accepted_denom(&quote.denom).is_none()
+                || self.quote_denoms[..index]
+                    .iter()
+                    .any(|other| other.denom == quote.denom)
+            {
+                return Err(ContractError::InvalidQuoteDenom {
+                    denom: quote.denom.clone(),
+                });
+            }
+        }
+
+        // Ticks must split the auction into equal steps
+        if let Some(step_interval) = self.step_interval {
+            if step_interval == 0
+                || step_interval > duration
+                || !duration.is_multiple_of(step_interval)
+            {
+                return Err(ContractError::InvalidStepInterval {
+                    step_interval,
+                    duration,
+                });
+            }
+        }
+
         Ok(())
     }
 
@@ -101,20 +361,322 @@ impl Auction {
         self.start_time <= now
     }
 
+    pub fn phase(&self, now: Timestamp) -> AuctionPhase {
+        if self.is_sold_out(now) {
+            return AuctionPhase::SoldOut;
+        }
+        match self.status {
+            AuctionStatus::SoldOut => AuctionPhase::SoldOut,
+            AuctionStatus::Finalized | AuctionStatus::Cancelled | AuctionStatus::Failed => {
+                AuctionPhase::Ended
+            }
+            AuctionStatus::Open if !self.is_started(now) => AuctionPhase::Pending,
+            AuctionStatus::Open if self.is_expired(now) => AuctionPhase::Ended,
+            AuctionStatus::Open => AuctionPhase::Active,
+        }
+    }
+
+    /// A uniform price auction also sells out once the price has dropped far
+    /// enough for its commitments to cover the whole supply.
+    pub fn is_sold_out(&self, now: Timestamp) -> bool {
+        match self.settlement_mode {
+            _ if self.status == AuctionStatus::SoldOut => true,
+            SettlementMode::UniformPrice if self.is_open() && self.is_active(now) => {
+                self.commit_capacity(self.calculate_price(now)).is_zero()
+            }
+            _ => false,
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.status == AuctionStatus::Open
+    }
+
+    /// Whether bought tokens and proceeds are held until finalization.
+    pub fn is_escrowed(&self) -> bool {
+        self.min_raise.is_some()
+    }
+
+    /// Whether bought tokens are held by the contract until claimed.
+    pub fn defers_delivery(&self) -> bool {
+        self.is_escrowed() || self.token_delivery != TokenDelivery::Instant
+    }
+
+    /// Part of the `acquired` tokens a bidder may have received by `now`.
+    pub fn unlocked_amount(&self, acquired: Uint128, now: Timestamp) -> Uint128 {
+        if self.status == AuctionStatus::Failed
+            || (self.is_escrowed() && self.status != AuctionStatus::Finalized)
+        {
+            return Uint128::zero();
+        }
+        match &self.token_delivery {
+            TokenDelivery::Instant => acquired,
+            TokenDelivery::Deferred { claim_start } => {
+                if now >= claim_start.unwrap_or(self.end_time) {
+                    acquired
+                } else {
+                    Uint128::zero()
+                }
+            }
+            TokenDelivery::Vesting(schedule) => {
+                schedule.vested_amount(acquired, self.end_time, now)
+            }
+        }
+    }
+
+    /// Whether `raised` is short of the minimum raise.
+    pub fn misses_min_raise(&self, raised: Uint128) -> bool {
+        self.min_raise.is_some_and(|min_raise| raised < min_raise)
+    }
+
+    /// Proceeds released to the creator by `now`, claimed ones included.
+    /// Escrowed proceeds are only released once the auction is finalized.
+    pub fn vested_proceeds(&self, now: Timestamp) -> Uint128 {
+        self.released_amount(self.total_raised, now)
+    }
+
+    /// Part of `raised` proceeds, in any denom, released to the creator by `now`.
+    pub fn released_amount(&self, raised: Uint128, now: Timestamp) -> Uint128 {
+        if self.is_escrowed() && self.status != AuctionStatus::Finalized {
+            return Uint128::zero();
+        }
+        match &self.proceeds_vesting {
+            Some(schedule) => schedule.vested_amount(raised, self.end_time, now),
+            None => raised,
+        }
+    }
+
+    pub fn claimable_proceeds(&self, now: Timestamp) -> Uint128 {
+        self.vested_proceeds(now).saturating_sub(self.total_claimed)
+    }
+
+    /// Rate of a payment in `payment` to `in_denom`, if bids may be paid with it.
+    pub fn payment_rate(&self, payment: &AssetInfo) -> Option<Decimal> {
+        if *payment == self.in_asset {
+            return Some(Decimal::one());
+        }
+        match payment {
+            AssetInfo::Native { denom } => self
+                .quote_denoms
+                .iter()
+                .find(|quote| quote.denom == *denom)
+                .map(|quote| quote.rate),
+            AssetInfo::Cw20 { .. } => None,
+        }
+    }
+
+    /// Quotes a bid paying `paid_amount` of a denom worth `rate` in `in_denom`.
+    /// The cost and refund are in the paid denom, rounded in the auction's favour.
+    pub fn quote_payment(
+        &self,
+        paid_amount: Uint128,
+        rate: Decimal,
+        now: Timestamp,
+        params: &Params,
+    ) -> Result<BidOutcome, ContractError> {
+        let outcome = self.quote_bid(paid_amount.mul_floor(rate), now, params)?;
+        let cost = outcome.cost.div_ceil(rate).min(paid_amount);
+        Ok(BidOutcome {
+            cost,
+            refund_amount: paid_amount - cost,
+            ..outcome
+        })
+    }
+
+    /// Runs every check a bid of `paid_amount` placed at `now` goes through and
+    /// fills it at the price of that moment.
+    pub fn quote_bid(
+        &self,
+        paid_amount: Uint128,
+        now: Timestamp,
+        params: &Params,
+    ) -> Result<BidOutcome, ContractError> {
+        if self.status == AuctionStatus::SoldOut {
+            return Err(ContractError::AuctionSoldOut {});
+        }
+        if !self.is_open() || !self.is_active(now) {
+            return Err(ContractError::AuctionNotActive {});
+        }
+
+        if let Some(min_bid_size) = params
+            .accepted_denom(&self.in_denom)
+            .and_then(|accepted| accepted.min_bid_size)
+        {
+            if paid_amount < min_bid_size {
+                return Err(ContractError::BidTooSmall {
+                    min_bid_size,
+                    amount: paid_amount,
+                });
+            }
+        }
+
+        if let Some(max_bid_size) = self.max_bid_size {
+            if paid_amount > max_bid_size {
+                return Err(ContractError::BidTooLarge {
+                    max_bid_size,
+                    amount: paid_amount,
+                });
+            }
+        }
+
+        let price = self.calculate_price(now);
+        match self.settlement_mode {
+            SettlementMode::PayAsBid => self.fill_bid(paid_amount, price),
+            SettlementMode::UniformPrice => self.commit_bid(paid_amount, price),
+        }
+    }
+
+    /// Payment a uniform price auction can still take at `price` before its
+    /// commitments cover the whole supply.
+    pub fn commit_capacity(&self, price: Decimal) -> Uint128 {
+        self.offered_asset
+            .amount
+            .mul_ceil(price)
+            .saturating_sub(self.total_committed)
+    }
+
+    /// Commits a bid of `paid_amount` at `price`. The acquired amount is the
+    /// least the bidder receives, as the clearing price never exceeds `price`.
+    pub fn commit_bid(
+        &self,
+        paid_amount: Uint128,
+        price: Decimal,
+    ) -> Result<BidOutcome, ContractError> {
+        let capacity = self.commit_capacity(price);
+        if capacity.is_zero() {
+            return Err(ContractError::AuctionSoldOut {});
+        }
+
+        let cost = paid_amount.min(capacity);
+        let acquired_amount = Decimal::from_ratio(cost.u128(), 1u128)
+            .checked_div(price)?
+            .to_uint_floor();
+        if acquired_amount.is_zero() {
+            return Err(ContractError::BidTooSmallForPrice { price });
+        }
+
+        Ok(BidOutcome {
+            price,
+            acquired_amount,
+            cost,
+            refund_amount: paid_amount.checked_sub(cost)?,
+        })
+    }
+
+    /// Price at which the committed payments buy the whole supply, but never
+    /// below the end price.
+    pub fn uniform_clearing_price(&self) -> Decimal {
+        Decimal::from_ratio(self.total_committed, self.offered_asset.amount).max(self.end_price)
+    }
+
+    /// Most tokens the commitments buy at `clearing_price`, the per bidder
+    /// rounding may leave a few of them unsold.
+    pub fn uniform_sold_amount(&self, clearing_price: Decimal) -> Result<Uint128, ContractError> {
+        if self.total_committed.is_zero() {
+            return Ok(Uint128::zero());
+        }
+        let sold_amount = Decimal::from_ratio(self.total_committed, 1u128)
+            .checked_div(clearing_price)?
+            .to_uint_floor();
+        Ok(sold_amount.min(self.offered_asset.amount))
+    }
+
+    /// Proceeds of a settling uniform price auction the creator is sure to keep.
+    /// Each open commitment may still be refunded up to one unit of the clearing
+    /// price, which stays reserved until it is settled.
+    pub fn uniform_proceeds(
+        &self,
+        clearing_price: Decimal,
+        settlement: &Settlement,
+    ) -> Result<Uint128, ContractError> {
+        let open_committed = self
+            .total_committed
+            .checked_sub(settlement.settled_committed)?;
+        let reserved = clearing_price
+            .to_uint_ceil()
+            .checked_mul(settlement.open_commitments.into())?;
+        Ok(settlement
+            .settled_cost
+            .checked_add(open_committed.saturating_sub(reserved))?)
+    }
+
+    /// Settles a uniform price commitment at `clearing_price`. The bidder's share
+    /// is floored to whole tokens and the unused payment refunded.
+    pub fn settle_commitment(
+        committed: Uint128,
+        clearing_price: Decimal,
+    ) -> Result<BidOutcome, ContractError> {
+        let acquired_amount = Decimal::from_ratio(committed, 1u128)
+            .checked_div(clearing_price)?
+            .to_uint_floor();
+        let cost = acquired_amount.mul_ceil(clearing_price);
+        Ok(BidOutcome {
+            price: clearing_price,
+            acquired_amount,
+            cost,
+            refund_amount: committed.checked_sub(cost)?,
+        })
+    }
+
+    /// Fills a bid of `paid_amount` at `price`. Only whole tokens are bought and
+    /// the bidder is charged their exact cost, a bid worth more than what is
+    /// left buys out the remaining supply. Any unused payment is refunded.
+    pub fn fill_bid(
+        &self,
+        paid_amount: Uint128,
+        price: Decimal,
+    ) -> Result<BidOutcome, ContractError> {
+        let acquired_amount = Decimal::from_ratio(paid_amount.u128(), 1u128)
+            .checked_div(price)?
+            .to_uint_floor();
+
+        if acquired_amount.is_zero() {
+            return Err(ContractError::BidTooSmallForPrice { price });
+        }
+
+        let acquired_amount = acquired_amount.min(self.remaining_amount);
+        let cost = acquired_amount.mul_ceil(price);
+        Ok(BidOutcome {
+            price,
+            acquired_amount,
+            cost,
+            refund_amount: paid_amount.checked_sub(cost)?,
+        })
+    }
+
+    /// Time of the next price tick for stepped auctions that have not ended.
+    pub fn next_price_change(&self, now: Timestamp) -> Option<Timestamp> {
+        let step_interval = self.step_interval?;
+        if now >= self.end_time {
+            return None;
+        }
+        if now < self.start_time {
+            return Some(self.start_time.plus_seconds(step_interval));
+        }
+        let time_passed = now.seconds() - self.start_time.seconds();
+        let next_tick = (time_passed / step_interval + 1) * step_interval;
+        Some(self.start_time.plus_seconds(next_tick).min(self.end_time))
+    }
+
     pub fn calculate_price(&self, now: Timestamp) -> Decimal {
+        if now <= self.start_time {
+            return self.starting_price;
+        }
+        if now >= self.end_time {
+            return self.end_price;
+        }
         let total_duration = self.end_time.minus_nanos(self.start_time.nanos()).nanos();
-        let time_passed = now.minus_nanos(self.start_time.nanos()).nanos();
-        let price_diff = self.starting_price - self.end_price;
-        let price_diff = price_diff
-            .checked_mul(Decimal::from_ratio(
-                Uint128::from(time_passed as u128),
-                Uint128::from(total_duration as u128),
-            ))
-            .ok();
-        match price_diff {
-            Some(price_diff) => self.starting_price - price_diff,
-            None => self.end_price,
+        let mut time_passed = now.minus_nanos(self.start_time.nanos()).nanos();
+        if let Some(step_interval) = self.step_interval {
+            let step_nanos = Timestamp::from_seconds(step_interval).nanos();
+            time_passed -= time_passed % step_nanos;
         }
+        self.price_curve.price_at(
+            self.starting_price,
+            self.end_price,
+            time_passed,
+            total_duration,
+        )
     }
 }
 
@@ -124,6 +686,7 @@ mod tests {
     use std::str::FromStr;
 
     use super::*;
+    use crate::state::AcceptedDenom;
     use cosmwasm_std::{Addr, Timestamp};
 
     // #[test]
@@ -168,13 +731,35 @@ mod tests {
             offered_asset: Coin {
                 denom: "ubtc".to_string(),
                 amount: Uint128::from(1000u128),
-            },
+            }
+            .into(),
             in_denom: "uusd".to_string(),
+            in_asset: AssetInfo::Native {
+                denom: "uusd".to_string(),
+            },
             starting_price: Decimal::from_str("100").unwrap(),
             end_price: Decimal::from_str("10").unwrap(),
             start_time: Timestamp::from_seconds(1500),
             end_time: Timestamp::from_seconds(3000),
             remaining_amount: Uint128::from(1000u128),
+            total_raised: Uint128::zero(),
+            total_claimed: Uint128::zero(),
+            status: AuctionStatus::Open,
+            held_creation_fee: None,
+            price_curve: PriceCurve::Linear,
+            step_interval: None,
+            settlement_mode: SettlementMode::PayAsBid,
+            total_committed: Uint128::zero(),
+            clearing_price: None,
+            max_per_wallet: None,
+            max_bid_size: None,
+            allowlist_only: false,
+            merkle_root: None,
+            min_raise: None,
+            token_delivery: TokenDelivery::Instant,
+            proceeds_vesting: None,
+            quote_denoms: vec![],
+            mint_offered: false,
         };
 
         let params = Params {
@@ -185,7 +770,13 @@ mod tests {
             admin: Addr::unchecked("admin"),
             min_seconds_until_auction_start: 1000,
             max_auction_duration: 2000,
-            accepted_denoms: vec!["uusd".to_string()],
+            accepted_denoms: vec![AcceptedDenom {
+                denom: "uusd".to_string(),
+                min_starting_price: None,
+                min_bid_size: None,
+            }],
+            cancellation_fee_refund: Decimal::zero(),
+            max_vesting_duration: 10_000,
         };
 
         let now = Timestamp::from_seconds(1400);
@@ -202,6 +793,96 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_validate_accepted_denoms() {
+        let mut auction = Auction::new(
+            "creator".to_string(),
+            Coin {
+                denom: "ubtc".to_string(),
+                amount: Uint128::from(1000u128),
+            }
+            .into(),
+            "uluna".to_string(),
+            Decimal::from_str("100").unwrap(),
+            Decimal::from_str("10").unwrap(),
+            Timestamp::from_seconds(3000),
+            Timestamp::from_seconds(4000),
+            PriceCurve::Linear,
+        );
+
+        let params = Params {
+            auction_creation_fee: Coin {
+                denom: "uusd".to_string(),
+                amount: Uint128::from(100u128),
+            },
+            admin: Addr::unchecked("admin"),
+            min_seconds_until_auction_start: 1000,
+            max_auction_duration: 2000,
+            accepted_denoms: vec![AcceptedDenom {
+                denom: "uusd".to_string(),
+                min_starting_price: Some(Decimal::from_str("150").unwrap()),
+                min_bid_size: None,
+            }],
+            cancellation_fee_refund: Decimal::zero(),
+            max_vesting_duration: 10_000,
+        };
+
+        let now = Timestamp::from_seconds(1000);
+        let result = auction.validate(now, params.clone());
+        assert!(matches!(
+            result,
+            Err(ContractError::InDenomNotAccepted { denom }) if denom == "uluna"
+        ));
+
+        auction.in_denom = "uusd".to_string();
+        let result = auction.validate(now, params.clone());
+        assert!(matches!(
+            result,
+            Err(ContractError::StartingPriceTooLow { min_starting_price, .. })
+                if min_starting_price == Decimal::from_str("150").unwrap()
+        ));
+
+        auction.starting_price = Decimal::from_str("150").unwrap();
+        assert!(auction.validate(now, params).is_ok());
+    }
+
+    #[test]
+    fn test_validate_zero_offered_amount() {
+        let auction = Auction::new(
+            "creator".to_string(),
+            Coin {
+                denom: "ubtc".to_string(),
+                amount: Uint128::zero(),
+            }
+            .into(),
+            "uusd".to_string(),
+            Decimal::from_str("100").unwrap(),
+            Decimal::from_str("10").unwrap(),
+            Timestamp::from_seconds(3000),
+            Timestamp::from_seconds(4000),
+            PriceCurve::Linear,
+        );
+        let params = Params {
+            auction_creation_fee: Coin {
+                denom: "uusd".to_string(),
+                amount: Uint128::from(100u128),
+            },
+            admin: Addr::unchecked("admin"),
+            min_seconds_until_auction_start: 1000,
+            max_auction_duration: 2000,
+            accepted_denoms: vec![AcceptedDenom {
+                denom: "uusd".to_string(),
+                min_starting_price: None,
+                min_bid_size: None,
+            }],
+            cancellation_fee_refund: Decimal::zero(),
+            max_vesting_duration: 10_000,
+        };
+
+        let result = auction.validate(Timestamp::from_seconds(1000), params);
+        assert!(matches!(result, Err(ContractError::ZeroOfferedAmount {})));
+    }
+
     #[test]
     fn test_calculate_price() {
         let auction = Auction {
@@ -209,17 +890,164 @@ mod tests {
             offered_asset: Coin {
                 denom: "ubtc".to_string(),
                 amount: Uint128::from(1000u128),
-            },
+            }
+            .into(),
             in_denom: "uusd".to_string(),
+            in_asset: AssetInfo::Native {
+                denom: "uusd".to_string(),
+            },
             starting_price: Decimal::from_str("100").unwrap(),
             end_price: Decimal::from_str("10").unwrap(),
             start_time: Timestamp::from_seconds(1000),
             end_time: Timestamp::from_seconds(2000),
             remaining_amount: Uint128::from(1000u128),
+            total_raised: Uint128::zero(),
+            total_claimed: Uint128::zero(),
+            status: AuctionStatus::Open,
+            held_creation_fee: None,
+            price_curve: PriceCurve::Linear,
+            step_interval: None,
+            settlement_mode: SettlementMode::PayAsBid,
+            total_committed: Uint128::zero(),
+            clearing_price: None,
+            max_per_wallet: None,
+            max_bid_size: None,
+            allowlist_only: false,
+            merkle_root: None,
+            min_raise: None,
+            token_delivery: TokenDelivery::Instant,
+            proceeds_vesting: None,
+            quote_denoms: vec![],
+            mint_offered: false,
         };
 
         let now = Timestamp::from_seconds(1500);
         let price = auction.calculate_price(now);
         assert_eq!(price, Decimal::from_str("55").unwrap());
     }
+
+    #[test]
+    fn test_calculate_stepped_price() {
+        let mut auction = Auction::new(
+            "creator".to_string(),
+            Coin {
+                denom: "ubtc".to_string(),
+                amount: Uint128::from(1000u128),
+            }
+            .into(),
+            "uusd".to_string(),
+            Decimal::from_str("100").unwrap(),
+            Decimal::from_str("10").unwrap(),
+            Timestamp::from_seconds(1000),
+            Timestamp::from_seconds(2000),
+            PriceCurve::Linear,
+        );
+        auction.step_interval = Some(250);
+
+        // Every bid within a tick pays the same price
+        for seconds in [1500, 1600, 1749] {
+            let price = auction.calculate_price(Timestamp::from_seconds(seconds));
+            assert_eq!(price, Decimal::from_str("55").unwrap());
+        }
+        assert_eq!(
+            auction.next_price_change(Timestamp::from_seconds(1600)),
+            Some(Timestamp::from_seconds(1750))
+        );
+        assert_eq!(
+            auction.next_price_change(Timestamp::from_seconds(500)),
+            Some(Timestamp::from_seconds(1250))
+        );
+        assert_eq!(
+            auction.calculate_price(Timestamp::from_seconds(1999)),
+            Decimal::from_str("32.5").unwrap()
+        );
+        assert_eq!(
+            auction.next_price_change(Timestamp::from_seconds(1999)),
+            Some(Timestamp::from_seconds(2000))
+        );
+        assert_eq!(
+            auction.next_price_change(Timestamp::from_seconds(2000)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_commit_bid_and_clearing_price() {
+        let mut auction = Auction::new(
+            "creator".to_string(),
+            Coin {
+                denom: "ubtc".to_string(),
+                amount: Uint128::from(1000u128),
+            }
+            .into(),
+            "uusd".to_string(),
+            Decimal::from_str("100").unwrap(),
+            Decimal::from_str("10").unwrap(),
+            Timestamp::from_seconds(1000),
+            Timestamp::from_seconds(2000),
+            PriceCurve::Linear,
+        );
+        auction.settlement_mode = SettlementMode::UniformPrice;
+        assert_eq!(
+            auction.uniform_clearing_price(),
+            Decimal::from_str("10").unwrap()
+        );
+
+        // Commitments can cover at most the whole supply at the current price
+        auction.total_committed = Uint128::new(40_000);
+        let price = Decimal::from_str("50").unwrap();
+        let outcome = auction.commit_bid(Uint128::new(15_000), price).unwrap();
+        assert_eq!(outcome.cost, Uint128::new(10_000));
+        assert_eq!(outcome.refund_amount, Uint128::new(5_000));
+        assert_eq!(outcome.acquired_amount, Uint128::new(200));
+
+        auction.total_committed = Uint128::new(50_000);
+        assert_eq!(
+            auction.uniform_clearing_price(),
+            Decimal::from_str("50").unwrap()
+        );
+        assert!(matches!(
+            auction.commit_bid(Uint128::new(100), price),
+            Err(ContractError::AuctionSoldOut {})
+        ));
+        assert!(auction.is_sold_out(Timestamp::from_seconds(1600)));
+        assert!(!auction.is_sold_out(Timestamp::from_seconds(1500)));
+    }
+
+    #[test]
+    fn test_fill_bid_refunds_dust() {
+        let auction = Auction::new(
+            "creator".to_string(),
+            Coin {
+                denom: "ubtc".to_string(),
+                amount: Uint128::from(1000u128),
+            }
+            .into(),
+            "uusd".to_string(),
+            Decimal::from_str("100").unwrap(),
+            Decimal::from_str("10").unwrap(),
+            Timestamp::from_seconds(1000),
+            Timestamp::from_seconds(2000),
+            PriceCurve::Linear,
+        );
+        let price = Decimal::from_str("2.5").unwrap();
+
+        let outcome = auction.fill_bid(Uint128::new(11), price).unwrap();
+        assert_eq!(outcome.acquired_amount, Uint128::new(4));
+        assert_eq!(outcome.cost, Uint128::new(10));
+        assert_eq!(outcome.refund_amount, Uint128::new(1));
+
+        // Fractional costs are rounded up in favour of the auction
+        let price = Decimal::from_str("2.4").unwrap();
+        let outcome = auction.fill_bid(Uint128::new(11), price).unwrap();
+        assert_eq!(outcome.acquired_amount, Uint128::new(4));
+        assert_eq!(outcome.cost, Uint128::new(10));
+        assert_eq!(outcome.refund_amount, Uint128::new(1));
+
+        let result = auction.fill_bid(Uint128::new(2), price);
+        assert!(matches!(
+            result,
+            Err(ContractError::BidTooSmallForPrice { .. })
+        ));
+    }
 }
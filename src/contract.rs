@@ -1,17 +1,39 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_json_binary, BankMsg, Binary, Coin, Decimal, Deps, DepsMut, Env, MessageInfo, Order,
-    Response, StdResult, Timestamp, Uint128,
+    from_json, to_json_binary, Addr, BankMsg, Binary, Coin, Decimal, Deps, DepsMut, Empty, Env,
+    MessageInfo, Order, StdResult, Timestamp, Uint128,
 };
+use cw20::Cw20ReceiveMsg;
 use cw_storage_plus::Bound;
-use cw_utils::must_pay;
+use cw_utils::{one_coin, PaymentError};
+use semver::Version;
 
-use crate::auction::Auction;
+use crate::asset::{Asset, AssetInfo};
+use crate::auction::{Auction, AuctionStatus, BidOutcome, SettlementMode};
 use crate::error::ContractError;
 use crate::helpers::check_payment;
-use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
-use crate::state::{Params, AUCTIONS, AUCTION_INDEX, PARAMS};
+use crate::merkle::{self, MerkleProof};
+use crate::migrations::{migrate_state, v0_1};
+use crate::msg::{
+    AuctionOptions, ClaimableResponse, CurrentPriceResponse, Cw20HookMsg, DenomProceeds,
+    ExecuteMsg, InstantiateMsg, MigrateMsg, ProceedsResponse, QueryMsg, SimulateBidResponse,
+};
+use crate::state::{
+    AcceptedDenom, BidderPosition, Params, ALLOWLIST, AUCTIONS, AUCTION_INDEX, BIDS, COMMITMENTS,
+    CREATION_FEE_DEPOSITS, DEFAULT_MAX_VESTING_DURATION, PARAMS, QUOTE_PROCEEDS, SETTLEMENTS,
+};
+#[cfg(feature = "tokenfactory")]
+use crate::token_factory::{self, TokenFactoryMsg};
+
+/// Custom message of the chain the contract is built for
+#[cfg(feature = "tokenfactory")]
+pub type ContractMsg = TokenFactoryMsg;
+#[cfg(not(feature = "tokenfactory"))]
+pub type ContractMsg = Empty;
+
+type Response = cosmwasm_std::Response<ContractMsg>;
+type CosmosMsg = cosmwasm_std::CosmosMsg<ContractMsg>;
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:dutch-auction-launchpad";
@@ -33,6 +55,16 @@ pub fn instantiate(
     if msg.max_auction_duration == 0 {
         return Err(ContractError::InvalidParams {});
     }
+    let cancellation_fee_refund = msg.cancellation_fee_refund.unwrap_or_default();
+    if cancellation_fee_refund > Decimal::one() {
+        return Err(ContractError::InvalidParams {});
+    }
+    let max_vesting_duration = msg
+        .max_vesting_duration
+        .unwrap_or(DEFAULT_MAX_VESTING_DURATION);
+    if max_vesting_duration == 0 {
+        return Err(ContractError::InvalidParams {});
+    }
 
     let admin = deps.api.addr_validate(&msg.admin)?;
 
@@ -43,6 +75,8 @@ pub fn instantiate(
         min_seconds_until_auction_start: msg.min_seconds_until_auction_start,
         max_auction_duration: msg.max_auction_duration,
         accepted_denoms: msg.accepted_denoms,
+        cancellation_fee_refund,
+        max_vesting_duration,
     };
 
     PARAMS.save(deps.storage, &params)?;
@@ -61,28 +95,61 @@ pub fn execute(
     match msg {
         ExecuteMsg::CreateAuction {
             offered_asset,
-            in_denom,
-            starting_price,
-            end_price,
-            start_time,
-            end_time,
-        } => execute_create_auction(
-            deps,
-            env,
-            info,
-            offered_asset,
-            in_denom,
-            starting_price,
-            end_price,
-            start_time,
-            end_time,
-        ),
-        ExecuteMsg::Bid { auction_id } => execute_bid(deps, env, info, auction_id),
+            options,
+        } => {
+            let params = PARAMS.load(deps.storage)?;
+            let expected_funds = vec![params.auction_creation_fee, offered_asset.clone()];
+            // Check if the sent funds are correct
+            check_payment(&info.funds, &expected_funds)?;
+
+            execute_create_auction(deps, env, info.sender, offered_asset.into(), false, options)
+        }
+        #[cfg(feature = "tokenfactory")]
+        ExecuteMsg::CreateTokenAuction {
+            subdenom,
+            supply,
+            options,
+        } => {
+            let params = PARAMS.load(deps.storage)?;
+            check_payment(&info.funds, &[params.auction_creation_fee])?;
+
+            let offered_asset = Asset {
+                info: AssetInfo::Native {
+                    denom: token_factory::full_denom(&env.contract.address, &subdenom),
+                },
+                amount: supply,
+            };
+            let res = execute_create_auction(deps, env, info.sender, offered_asset, true, options)?;
+            Ok(res.add_message(TokenFactoryMsg::CreateDenom { subdenom }))
+        }
+        ExecuteMsg::Receive(msg) => execute_receive(deps, env, info, msg),
+        ExecuteMsg::DepositCreationFee {} => execute_deposit_creation_fee(deps, env, info),
+        ExecuteMsg::WithdrawCreationFee {} => execute_withdraw_creation_fee(deps, env, info),
+        ExecuteMsg::Bid {
+            auction_id,
+            max_price,
+            min_amount_out,
+            merkle_proof,
+        } => {
+            let payment = one_coin(&info)?.into();
+            execute_bid(
+                deps,
+                env,
+                info.sender,
+                payment,
+                auction_id,
+                max_price,
+                min_amount_out,
+                merkle_proof,
+            )
+        }
         ExecuteMsg::ChangeParams {
             auction_creation_fee,
             min_seconds_until_auction_start,
             max_auction_duration,
             accepted_denoms,
+            cancellation_fee_refund,
+            max_vesting_duration,
             admin,
         } => execute_change_params(
             deps,
@@ -92,115 +159,479 @@ pub fn execute(
             min_seconds_until_auction_start,
             max_auction_duration,
             accepted_denoms,
+            cancellation_fee_refund,
+            max_vesting_duration,
             admin,
         ),
         ExecuteMsg::CancelAuction { auction_id } => {
             execute_cancel_auction(deps, env, info, auction_id)
         }
+        ExecuteMsg::ClaimProceeds { auction_id } => {
+            execute_claim_proceeds(deps, env, info, auction_id)
+        }
+        ExecuteMsg::FinalizeAuction { auction_id } => {
+            execute_finalize_auction(deps, env, info, auction_id)
+        }
+        ExecuteMsg::AddToAllowlist {
+            auction_id,
+            addresses,
+        } => execute_update_allowlist(deps, env, info, auction_id, addresses, true),
+        ExecuteMsg::RemoveFromAllowlist {
+            auction_id,
+            addresses,
+        } => execute_update_allowlist(deps, env, info, auction_id, addresses, false),
+        ExecuteMsg::ClaimSettlement { auction_id } => {
+            execute_claim_settlement(deps, env, info, auction_id)
+        }
+        ExecuteMsg::ClaimTokens { auction_id } | ExecuteMsg::ClaimVested { auction_id } => {
+            execute_claim_tokens(deps, env, info, auction_id)
+        }
+        ExecuteMsg::ClaimRefund { auction_id } => execute_claim_refund(deps, env, info, auction_id),
     }
 }
 
 fn execute_create_auction(
     deps: DepsMut,
     env: Env,
-    info: MessageInfo,
-    offered_asset: Coin,
-    in_denom: String,
-    starting_price: Decimal,
-    end_price: Decimal,
-    start_time: Timestamp,
-    end_time: Timestamp,
+    creator: Addr,
+    offered_asset: Asset,
+    mint_offered: bool,
+    options: AuctionOptions,
 ) -> Result<Response, ContractError> {
     let params = PARAMS.load(deps.storage)?;
-    let funds = info.funds.clone();
-
-    let expected_funds = vec![params.auction_creation_fee.clone(), offered_asset.clone()];
-    // Check if the sent funds are correct
-    check_payment(&funds, &expected_funds)?;
+    let AuctionOptions {
+        in_denom,
+        starting_price,
+        end_price,
+        start_time,
+        end_time,
+        price_curve,
+        step_interval,
+        settlement_mode,
+        max_per_wallet,
+        max_bid_size,
+        allowlist_only,
+        merkle_root,
+        min_raise,
+        token_delivery,
+        proceeds_vesting,
+        quote_denoms,
+    } = options;
+    // Native denoms never validate as addresses
+    let in_asset = match deps.api.addr_validate(&in_denom) {
+        Ok(contract_addr) => AssetInfo::Cw20 { contract_addr },
+        Err(_) => AssetInfo::Native {
+            denom: in_denom.clone(),
+        },
+    };
 
-    let auction = Auction::new(
-        info.sender.to_string(),
+    let mut auction = Auction::new(
+        creator.to_string(),
         offered_asset.clone(),
         in_denom,
         starting_price,
         end_price,
         start_time,
         end_time,
+        price_curve.unwrap_or_default(),
     );
+    auction.in_asset = in_asset;
+    auction.mint_offered = mint_offered;
+    auction.step_interval = step_interval;
+    auction.settlement_mode = settlement_mode.unwrap_or_default();
+    auction.max_per_wallet = max_per_wallet;
+    auction.max_bid_size = max_bid_size;
+    auction.allowlist_only = allowlist_only.unwrap_or_default();
+    auction.merkle_root = merkle_root;
+    auction.min_raise = min_raise;
+    auction.token_delivery = token_delivery.unwrap_or_default();
+    auction.proceeds_vesting = proceeds_vesting;
+    auction.quote_denoms = quote_denoms.unwrap_or_default();
+    // Proceeds in quote denoms are only paid out with bank sends
+    if let Some(quote) = auction
+        .quote_denoms
+        .iter()
+        .find(|quote| deps.api.addr_validate(&quote.denom).is_ok())
+    {
+        return Err(ContractError::InvalidQuoteDenom {
+            denom: quote.denom.clone(),
+        });
+    }
 
     auction.validate(env.block.time, params.clone())?;
 
-    let updated_auction_index = AUCTION_INDEX.update(deps.storage, |index| -> StdResult<u8> {
+    // Hold back the refundable share of the fee until the auction starts
+    let held_fee_amount = params
+        .auction_creation_fee
+        .amount
+        .mul_floor(params.cancellation_fee_refund);
+    let admin_fee_amount = params
+        .auction_creation_fee
+        .amount
+        .checked_sub(held_fee_amount)?;
+    if !held_fee_amount.is_zero() {
+        auction.held_creation_fee = Some(Coin {
+            denom: params.auction_creation_fee.denom.clone(),
+            amount: held_fee_amount,
+        });
+    }
+
+    let updated_auction_index = AUCTION_INDEX.update(deps.storage, |index| -> StdResult<u64> {
         let new_index = index + 1;
         Ok(new_index)
     })?;
 
     AUCTIONS.save(deps.storage, updated_auction_index, &auction)?;
     let mut msgs = vec![];
-    if params.auction_creation_fee.amount > Uint128::zero() {
+    if admin_fee_amount > Uint128::zero() {
         msgs.push(BankMsg::Send {
             to_address: params.admin.to_string(),
-            amount: vec![params.auction_creation_fee.clone()],
+            amount: vec![Coin {
+                denom: params.auction_creation_fee.denom.clone(),
+                amount: admin_fee_amount,
+            }],
         });
     }
 
     let res: Response = Response::default()
         .add_attribute("action", "create_auction")
         .add_attribute("auction_id", updated_auction_index.to_string())
-        .add_attribute("creator", info.sender)
-        .add_attribute("offered_asset_denom", offered_asset.denom.to_string())
+        .add_attribute("creator", creator)
+        .add_attribute("offered_asset_denom", offered_asset.info.to_string())
         .add_attribute("offered_asset_amount", offered_asset.amount.to_string())
         .add_messages(msgs);
     Ok(res)
 }
 
-fn execute_bid(
+/// Handles CW20 transfers creating or bidding in auctions. The creation fee
+/// can not be attached to the transfer, it is taken from the sender's fee
+/// deposit.
+fn execute_receive(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    auction_id: u8,
+    msg: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    let sender = deps.api.addr_validate(&msg.sender)?;
+    let transferred = Asset {
+        info: AssetInfo::Cw20 {
+            contract_addr: info.sender,
+        },
+        amount: msg.amount,
+    };
+
+    match from_json(&msg.msg)? {
+        Cw20HookMsg::CreateAuction { options } => {
+            let params = PARAMS.load(deps.storage)?;
+            if !params.auction_creation_fee.amount.is_zero() {
+                let deposit = CREATION_FEE_DEPOSITS.may_load(deps.storage, &sender)?;
+                if deposit.as_ref() != Some(&params.auction_creation_fee) {
+                    return Err(ContractError::CreationFeeNotDeposited {});
+                }
+                CREATION_FEE_DEPOSITS.remove(deps.storage, &sender);
+            }
+
+            execute_create_auction(deps, env, sender, transferred, false, options)
+        }
+        Cw20HookMsg::Bid {
+            auction_id,
+            max_price,
+            min_amount_out,
+            merkle_proof,
+        } => execute_bid(
+            deps,
+            env,
+            sender,
+            transferred,
+            auction_id,
+            max_price,
+            min_amount_out,
+            merkle_proof,
+        ),
+    }
+}
+
+fn execute_deposit_creation_fee(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let params = PARAMS.load(deps.storage)?;
+    if CREATION_FEE_DEPOSITS.has(deps.storage, &info.sender) {
+        return Err(ContractError::CreationFeeAlreadyDeposited {});
+    }
+    check_payment(
+        &info.funds,
+        std::slice::from_ref(&params.auction_creation_fee),
+    )?;
+    CREATION_FEE_DEPOSITS.save(deps.storage, &info.sender, &params.auction_creation_fee)?;
+
+    let res: Response = Response::default()
+        .add_attribute("action", "deposit_creation_fee")
+        .add_attribute("creator", info.sender)
+        .add_attribute("fee", params.auction_creation_fee.to_string());
+    Ok(res)
+}
+
+fn execute_withdraw_creation_fee(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let deposit = CREATION_FEE_DEPOSITS
+        .may_load(deps.storage, &info.sender)?
+        .ok_or(ContractError::CreationFeeNotDeposited {})?;
+    CREATION_FEE_DEPOSITS.remove(deps.storage, &info.sender);
+
+    let msg = BankMsg::Send {
+        to_address: info.sender.to_string(),
+        amount: vec![deposit.clone()],
+    };
+
+    let res: Response = Response::default()
+        .add_attribute("action", "withdraw_creation_fee")
+        .add_attribute("creator", info.sender)
+        .add_attribute("fee", deposit.to_string())
+        .add_message(msg);
+    Ok(res)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn execute_bid(
+    deps: DepsMut,
+    env: Env,
+    bidder: Addr,
+    payment: Asset,
+    auction_id: u64,
+    max_price: Option<Decimal>,
+    min_amount_out: Option<Uint128>,
+    merkle_proof: Option<MerkleProof>,
 ) -> Result<Response, ContractError> {
     let mut auction = AUCTIONS
         .load(deps.storage, auction_id)
         .map_err(|_| ContractError::AuctionNotFound {})?;
 
-    if !auction.is_active(env.block.time) {
-        return Err(ContractError::AuctionNotActive {});
+    if auction.allowlist_only && !ALLOWLIST.has(deps.storage, (auction_id, &bidder)) {
+        return Err(ContractError::NotAllowlisted {});
     }
 
-    let price = auction.calculate_price(env.block.time);
-    let paid_amount = must_pay(&info, &auction.in_denom)?;
+    // Allocation proven against the merkle root, enforced once the purchase is known
+    let allocation = match &auction.merkle_root {
+        Some(root) => {
+            let merkle_proof = merkle_proof.ok_or(ContractError::MissingMerkleProof {})?;
+            if !merkle::verify(
+                root,
+                bidder.as_str(),
+                merkle_proof.allocation,
+                &merkle_proof.proof,
+            ) {
+                return Err(ContractError::InvalidMerkleProof {});
+            }
+            Some(merkle_proof.allocation)
+        }
+        None => None,
+    };
+
+    let rate = auction
+        .payment_rate(&payment.info)
+        .ok_or_else(|| PaymentError::MissingDenom(auction.in_denom.clone()))?;
+    let paid_amount = payment.amount;
+    let params = PARAMS.load(deps.storage)?;
 
-    let acquired_amount = Decimal::from_ratio(paid_amount.u128(), 1u128)
-        .checked_div(price)?
-        .to_uint_floor();
+    // Cost and refund are in the paid denom
+    let outcome = auction.quote_payment(paid_amount, rate, env.block.time, &params)?;
+    outcome.check_slippage(max_price, min_amount_out)?;
 
-    if acquired_amount >= auction.remaining_amount {
-        return Err(ContractError::InsufficientRemainingAmount {});
+    if auction.settlement_mode == SettlementMode::UniformPrice {
+        return commit_bid(deps, bidder, auction_id, auction, paid_amount, outcome);
     }
 
-    let acquired_asset = Coin {
-        denom: auction.offered_asset.denom.clone(),
-        amount: acquired_amount,
-    };
-    let msg = BankMsg::Send {
-        to_address: info.sender.to_string(),
-        amount: vec![acquired_asset.clone()],
-    };
-    auction.remaining_amount = auction.remaining_amount.checked_sub(acquired_amount)?;
+    // Deferred tokens are claimed once they unlock, minted ones are held in escrow until then
+    let mut msgs: Vec<CosmosMsg> = vec![];
+    if !auction.defers_delivery() {
+        msgs.push(deliver_offered(
+            &auction,
+            bidder.as_str(),
+            outcome.acquired_amount,
+        )?);
+    } else if auction.mint_offered {
+        msgs.push(deliver_offered(
+            &auction,
+            env.contract.address.as_str(),
+            outcome.acquired_amount,
+        )?);
+    }
+    if !outcome.refund_amount.is_zero() {
+        msgs.push(payment.info.transfer_msg(&bidder, outcome.refund_amount)?);
+    }
+
+    auction.remaining_amount = auction
+        .remaining_amount
+        .checked_sub(outcome.acquired_amount)?;
+    if payment.info == auction.in_asset {
+        auction.total_raised = auction.total_raised.checked_add(outcome.cost)?;
+    } else {
+        QUOTE_PROCEEDS.update(
+            deps.storage,
+            (auction_id, &payment.info.to_string()),
+            |proceeds| -> StdResult<_> {
+                let mut proceeds = proceeds.unwrap_or_default();
+                proceeds.total_raised = proceeds.total_raised.checked_add(outcome.cost)?;
+                Ok(proceeds)
+            },
+        )?;
+    }
+    if auction.remaining_amount.is_zero() {
+        auction.status = AuctionStatus::SoldOut;
+    }
     AUCTIONS.save(deps.storage, auction_id, &auction)?;
 
+    let mut position = BIDS
+        .may_load(deps.storage, (auction_id, &bidder))?
+        .unwrap_or_default();
+    position.total_paid = position
+        .total_paid
+        .checked_add(outcome.cost.mul_floor(rate))?;
+    position.total_acquired = position
+        .total_acquired
+        .checked_add(outcome.acquired_amount)?;
+    if !auction.defers_delivery() {
+        position.claimed_amount = position.total_acquired;
+    }
+    position.bid_count += 1;
+    if let Some(max_per_wallet) = auction.max_per_wallet {
+        if position.total_acquired > max_per_wallet {
+            return Err(ContractError::WalletCapExceeded {
+                max_per_wallet,
+                total_acquired: position.total_acquired,
+            });
+        }
+    }
+    if let Some(allocation) = allocation {
+        if position.total_acquired > allocation {
+            return Err(ContractError::AllocationExceeded {
+                allocation,
+                total_acquired: position.total_acquired,
+            });
+        }
+    }
+    BIDS.save(deps.storage, (auction_id, &bidder), &position)?;
+
     let res: Response = Response::default()
         .add_attribute("action", "bid")
         .add_attribute("auction_id", auction_id.to_string())
-        .add_attribute("bidder", info.sender)
+        .add_attribute("bidder", bidder)
         .add_attribute("amount", paid_amount.to_string())
-        .add_attribute("acquired_asset_denom", acquired_asset.denom)
-        .add_attribute("acquired_asset_amount", acquired_asset.amount.to_string())
-        .add_message(msg);
+        .add_attribute("denom", payment.info.to_string())
+        .add_attribute(
+            "acquired_asset_denom",
+            auction.offered_asset.info.to_string(),
+        )
+        .add_attribute("acquired_asset_amount", outcome.acquired_amount.to_string())
+        .add_attribute("refunded_amount", outcome.refund_amount.to_string())
+        .add_attribute("sold_out", auction.remaining_amount.is_zero().to_string())
+        .add_messages(msgs);
+    Ok(res)
+}
+
+/// Sends `amount` offered tokens to `recipient`, minting them for token factory auctions.
+fn deliver_offered(auction: &Auction, recipient: &str, amount: Uint128) -> StdResult<CosmosMsg> {
+    #[cfg(feature = "tokenfactory")]
+    if auction.mint_offered {
+        return Ok(TokenFactoryMsg::MintTokens {
+            denom: auction.offered_asset.info.to_string(),
+            amount,
+            mint_to_address: recipient.to_string(),
+        }
+        .into());
+    }
+    auction.offered_asset.info.transfer_msg(recipient, amount)
+}
+
+/// Hands the token factory denom of an auction over to its creator.
+#[cfg(feature = "tokenfactory")]
+fn release_denom_admin(auction: &Auction) -> Option<CosmosMsg> {
+    auction.mint_offered.then(|| {
+        TokenFactoryMsg::ChangeAdmin {
+            denom: auction.offered_asset.info.to_string(),
+            new_admin_address: auction.creator.clone(),
+        }
+        .into()
+    })
+}
+
+#[cfg(not(feature = "tokenfactory"))]
+fn release_denom_admin(_auction: &Auction) -> Option<CosmosMsg> {
+    None
+}
+
+/// Records the payment of a uniform price auction bid, tokens are only
+/// delivered once the clearing price is known.
+fn commit_bid(
+    deps: DepsMut,
+    bidder: Addr,
+    auction_id: u64,
+    mut auction: Auction,
+    paid_amount: Uint128,
+    outcome: BidOutcome,
+) -> Result<Response, ContractError> {
+    let capacity = auction.commit_capacity(outcome.price);
+    let committed = COMMITMENTS.may_load(deps.storage, (auction_id, &bidder))?;
+    if committed.is_none() {
+        SETTLEMENTS.update(deps.storage, auction_id, |settlement| -> StdResult<_> {
+            let mut settlement = settlement.unwrap_or_default();
+            settlement.open_commitments += 1;
+            Ok(settlement)
+        })?;
+    }
+    COMMITMENTS.save(
+        deps.storage,
+        (auction_id, &bidder),
+        &committed.unwrap_or_default().checked_add(outcome.cost)?,
+    )?;
+    auction.total_committed = auction.total_committed.checked_add(outcome.cost)?;
+    if outcome.cost == capacity {
+        auction.status = AuctionStatus::SoldOut;
+    }
+    AUCTIONS.save(deps.storage, auction_id, &auction)?;
+
+    // Tokens acquired are recorded once the commitment is settled
+    BIDS.update(
+        deps.storage,
+        (auction_id, &bidder),
+        |position| -> StdResult<_> {
+            let mut position = position.unwrap_or_default();
+            position.total_paid = position.total_paid.checked_add(outcome.cost)?;
+            position.bid_count += 1;
+            Ok(position)
+        },
+    )?;
+
+    let mut msgs = vec![];
+    if !outcome.refund_amount.is_zero() {
+        msgs.push(
+            auction
+                .in_asset
+                .transfer_msg(&bidder, outcome.refund_amount)?,
+        );
+    }
+
+    let res: Response = Response::default()
+        .add_attribute("action", "bid")
+        .add_attribute("auction_id", auction_id.to_string())
+        .add_attribute("bidder", bidder)
+        .add_attribute("amount", paid_amount.to_string())
+        .add_attribute("committed_amount", outcome.cost.to_string())
+        .add_attribute("refunded_amount", outcome.refund_amount.to_string())
+        .add_attribute(
+            "sold_out",
+            (auction.status == AuctionStatus::SoldOut).to_string(),
+        )
+        .add_messages(msgs);
     Ok(res)
 }
 
+#[allow(clippy::too_many_arguments)]
 fn execute_change_params(
     deps: DepsMut,
     _env: Env,
@@ -208,7 +639,9 @@ fn execute_change_params(
     auction_creation_fee: Option<Coin>,
     min_seconds_until_auction_start: Option<u64>,
     max_auction_duration: Option<u64>,
-    accepted_denoms: Option<Vec<String>>,
+    accepted_denoms: Option<Vec<AcceptedDenom>>,
+    cancellation_fee_refund: Option<Decimal>,
+    max_vesting_duration: Option<u64>,
     admin: Option<String>,
 ) -> Result<Response, ContractError> {
     let mut params = PARAMS.load(deps.storage)?;
@@ -228,6 +661,18 @@ fn execute_change_params(
     if let Some(accepted_denoms) = accepted_denoms {
         params.accepted_denoms = accepted_denoms;
     }
+    if let Some(cancellation_fee_refund) = cancellation_fee_refund {
+        if cancellation_fee_refund > Decimal::one() {
+            return Err(ContractError::InvalidParams {});
+        }
+        params.cancellation_fee_refund = cancellation_fee_refund;
+    }
+    if let Some(max_vesting_duration) = max_vesting_duration {
+        if max_vesting_duration == 0 {
+            return Err(ContractError::InvalidParams {});
+        }
+        params.max_vesting_duration = max_vesting_duration;
+    }
     if let Some(admin) = admin {
         let admin = deps.api.addr_validate(&admin)?;
         params.admin = admin;
@@ -243,9 +688,9 @@ fn execute_cancel_auction(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    auction_id: u8,
+    auction_id: u64,
 ) -> Result<Response, ContractError> {
-    let auction = AUCTIONS
+    let mut auction = AUCTIONS
         .load(deps.storage, auction_id)
         .map_err(|_| ContractError::AuctionNotFound {})?;
 
@@ -253,27 +698,546 @@ fn execute_cancel_auction(
         return Err(ContractError::Unauthorized {});
     }
 
-    if auction.is_started(env.block.time) {
+    if !auction.is_open() || auction.is_started(env.block.time) {
         return Err(ContractError::AuctionCannotBeCanceled {});
     }
 
-    AUCTIONS.remove(deps.storage, auction_id);
+    // Nothing of a token factory auction has been minted yet
+    let refunded_amount = if auction.mint_offered {
+        Uint128::zero()
+    } else {
+        auction.remaining_amount
+    };
+    let refunded_fee = auction.held_creation_fee.take();
+
+    // Keep the auction as a tombstone so its history stays queryable
+    auction.remaining_amount = Uint128::zero();
+    auction.status = AuctionStatus::Cancelled;
+    AUCTIONS.save(deps.storage, auction_id, &auction)?;
+
+    let mut msgs: Vec<CosmosMsg> = vec![];
+    if !refunded_amount.is_zero() {
+        msgs.push(
+            auction
+                .offered_asset
+                .info
+                .transfer_msg(&auction.creator, refunded_amount)?,
+        );
+    }
+    msgs.extend(release_denom_admin(&auction));
+    if let Some(refunded_fee) = refunded_fee.clone() {
+        msgs.push(
+            BankMsg::Send {
+                to_address: auction.creator.clone(),
+                amount: vec![refunded_fee],
+            }
+            .into(),
+        );
+    }
+    let refunded_fee_amount = refunded_fee.map(|fee| fee.amount).unwrap_or_default();
 
     let res: Response = Response::default()
         .add_attribute("action", "cancel_auction")
-        .add_attribute("auction_id", auction_id.to_string());
+        .add_attribute("auction_id", auction_id.to_string())
+        .add_attribute("creator", auction.creator)
+        .add_attribute(
+            "refunded_asset_denom",
+            auction.offered_asset.info.to_string(),
+        )
+        .add_attribute("refunded_asset_amount", refunded_amount.to_string())
+        .add_attribute("refunded_fee_amount", refunded_fee_amount.to_string())
+        .add_messages(msgs);
+    Ok(res)
+}
+
+fn execute_claim_proceeds(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    auction_id: u64,
+) -> Result<Response, ContractError> {
+    let mut auction = AUCTIONS
+        .load(deps.storage, auction_id)
+        .map_err(|_| ContractError::AuctionNotFound {})?;
+
+    if info.sender.to_string() != auction.creator {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let now = env.block.time;
+    let claimable = auction.claimable_proceeds(now);
+    let mut msgs: Vec<CosmosMsg> = vec![];
+    if !claimable.is_zero() {
+        auction.total_claimed = auction.total_claimed.checked_add(claimable)?;
+        AUCTIONS.save(deps.storage, auction_id, &auction)?;
+        msgs.push(auction.in_asset.transfer_msg(&auction.creator, claimable)?);
+    }
+
+    // Proceeds paid in quote denoms follow the same release schedule
+    let mut quote_claimed = vec![];
+    for quote in &auction.quote_denoms {
+        let key = (auction_id, quote.denom.as_str());
+        let Some(mut proceeds) = QUOTE_PROCEEDS.may_load(deps.storage, key)? else {
+            continue;
+        };
+        let quote_claimable = auction
+            .released_amount(proceeds.total_raised, now)
+            .saturating_sub(proceeds.total_claimed);
+        if quote_claimable.is_zero() {
+            continue;
+        }
+        proceeds.total_claimed = proceeds.total_claimed.checked_add(quote_claimable)?;
+        QUOTE_PROCEEDS.save(deps.storage, key, &proceeds)?;
+        quote_claimed.push(Coin {
+            denom: quote.denom.clone(),
+            amount: quote_claimable,
+        });
+    }
+    if !quote_claimed.is_empty() {
+        msgs.push(
+            BankMsg::Send {
+                to_address: auction.creator.clone(),
+                amount: quote_claimed.clone(),
+            }
+            .into(),
+        );
+    }
+    if msgs.is_empty() {
+        return Err(ContractError::NoProceedsToClaim {});
+    }
+
+    let mut res: Response = Response::default()
+        .add_attribute("action", "claim_proceeds")
+        .add_attribute("auction_id", auction_id.to_string())
+        .add_attribute("creator", auction.creator)
+        .add_attribute("proceeds_denom", auction.in_denom)
+        .add_attribute("proceeds_amount", claimable.to_string())
+        .add_messages(msgs);
+    if !quote_claimed.is_empty() {
+        let quote_claimed: Vec<String> = quote_claimed.iter().map(Coin::to_string).collect();
+        res = res.add_attribute("quote_proceeds", quote_claimed.join(","));
+    }
+    Ok(res)
+}
+
+fn execute_finalize_auction(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    auction_id: u64,
+) -> Result<Response, ContractError> {
+    let mut auction = AUCTIONS
+        .load(deps.storage, auction_id)
+        .map_err(|_| ContractError::AuctionNotFound {})?;
+
+    // A sold out auction can be settled right away
+    let now = env.block.time;
+    match auction.status {
+        AuctionStatus::Open if !auction.is_expired(now) && !auction.is_sold_out(now) => {
+            return Err(ContractError::AuctionNotEnded {})
+        }
+        AuctionStatus::Open | AuctionStatus::SoldOut => {}
+        AuctionStatus::Finalized | AuctionStatus::Failed => {
+            return Err(ContractError::AuctionAlreadyFinalized {})
+        }
+        AuctionStatus::Cancelled => return Err(ContractError::AuctionCancelled {}),
+    }
+
+    // Everyone in a uniform price auction buys at the clearing price. The
+    // commitments are only settled one by one when claimed, the creator is
+    // credited up front with what their rounding can not take back.
+    let (sold_amount, raised) = match auction.settlement_mode {
+        SettlementMode::UniformPrice => {
+            let clearing_price = auction.uniform_clearing_price();
+            (
+                auction.uniform_sold_amount(clearing_price)?,
+                auction.total_committed,
+            )
+        }
+        SettlementMode::PayAsBid => (
+            auction
+                .offered_asset
+                .amount
+                .checked_sub(auction.remaining_amount)?,
+            auction.total_raised,
+        ),
+    };
+
+    // A failed auction hands every sold token back, bidders reclaim their payments
+    let failed = auction.misses_min_raise(raised);
+    let mut returned_amount = if failed {
+        auction.status = AuctionStatus::Failed;
+        auction.offered_asset.amount
+    } else {
+        if auction.settlement_mode == SettlementMode::UniformPrice {
+            let clearing_price = auction.uniform_clearing_price();
+            let settlement = SETTLEMENTS
+                .may_load(deps.storage, auction_id)?
+                .unwrap_or_default();
+            auction.clearing_price = Some(clearing_price);
+            auction.total_raised = auction.uniform_proceeds(clearing_price, &settlement)?;
+        }
+        auction.status = AuctionStatus::Finalized;
+        auction.offered_asset.amount.checked_sub(sold_amount)?
+    };
+    let held_fee = auction.held_creation_fee.take();
+    auction.remaining_amount = Uint128::zero();
+    AUCTIONS.save(deps.storage, auction_id, &auction)?;
+
+    let mut msgs: Vec<CosmosMsg> = vec![];
+    // Only sold tokens of a token factory auction exist, uniform price sales
+    // are minted into escrow for the bidders to claim
+    if auction.mint_offered {
+        returned_amount = Uint128::zero();
+        match auction.settlement_mode {
+            SettlementMode::PayAsBid if failed => returned_amount = sold_amount,
+            SettlementMode::UniformPrice if !failed && !sold_amount.is_zero() => msgs.push(
+                deliver_offered(&auction, env.contract.address.as_str(), sold_amount)?,
+            ),
+            _ => {}
+        }
+    }
+    if !returned_amount.is_zero() {
+        msgs.push(
+            auction
+                .offered_asset
+                .info
+                .transfer_msg(&auction.creator, returned_amount)?,
+        );
+    }
+    msgs.extend(release_denom_admin(&auction));
+    // The auction could no longer be cancelled, the held fee belongs to the admin
+    if let Some(held_fee) = held_fee {
+        let params = PARAMS.load(deps.storage)?;
+        msgs.push(
+            BankMsg::Send {
+                to_address: params.admin.to_string(),
+                amount: vec![held_fee],
+            }
+            .into(),
+        );
+    }
+
+    let res: Response = Response::default()
+        .add_attribute("action", "finalize_auction")
+        .add_attribute("auction_id", auction_id.to_string())
+        .add_attribute("finalized_by", info.sender)
+        .add_attribute("creator", auction.creator)
+        .add_attribute("returned_amount", returned_amount.to_string())
+        .add_attribute("failed", failed.to_string())
+        .add_attribute(
+            "clearing_price",
+            auction
+                .clearing_price
+                .map_or_else(String::new, |price| price.to_string()),
+        )
+        .add_messages(msgs);
+    Ok(res)
+}
+
+fn execute_update_allowlist(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    auction_id: u64,
+    addresses: Vec<String>,
+    allowed: bool,
+) -> Result<Response, ContractError> {
+    let auction = AUCTIONS
+        .load(deps.storage, auction_id)
+        .map_err(|_| ContractError::AuctionNotFound {})?;
+
+    if info.sender.to_string() != auction.creator {
+        return Err(ContractError::Unauthorized {});
+    }
+    if !auction.allowlist_only {
+        return Err(ContractError::AllowlistNotEnabled {});
+    }
+
+    for address in &addresses {
+        let address = deps.api.addr_validate(address)?;
+        if allowed {
+            ALLOWLIST.save(deps.storage, (auction_id, &address), &Empty {})?;
+        } else {
+            ALLOWLIST.remove(deps.storage, (auction_id, &address));
+        }
+    }
+
+    let action = if allowed {
+        "add_to_allowlist"
+    } else {
+        "remove_from_allowlist"
+    };
+    let res: Response = Response::default()
+        .add_attribute("action", action)
+        .add_attribute("auction_id", auction_id.to_string())
+        .add_attribute("addresses", addresses.join(","));
+    Ok(res)
+}
+
+fn execute_claim_settlement(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    auction_id: u64,
+) -> Result<Response, ContractError> {
+    let mut auction = AUCTIONS
+        .load(deps.storage, auction_id)
+        .map_err(|_| ContractError::AuctionNotFound {})?;
+
+    let committed = COMMITMENTS
+        .may_load(deps.storage, (auction_id, &info.sender))?
+        .ok_or(ContractError::NoCommitment {})?;
+    let clearing_price = auction
+        .clearing_price
+        .ok_or(ContractError::AuctionNotSettled {})?;
+
+    let BidOutcome {
+        acquired_amount,
+        cost,
+        refund_amount,
+        ..
+    } = Auction::settle_commitment(committed, clearing_price)?;
+
+    COMMITMENTS.remove(deps.storage, (auction_id, &info.sender));
+
+    // Settling releases the reserve the commitment held on the creator's proceeds
+    let mut settlement = SETTLEMENTS.load(deps.storage, auction_id)?;
+    settlement.open_commitments -= 1;
+    settlement.settled_committed = settlement.settled_committed.checked_add(committed)?;
+    settlement.settled_cost = settlement.settled_cost.checked_add(cost)?;
+    settlement.settled_amount = settlement.settled_amount.checked_add(acquired_amount)?;
+    SETTLEMENTS.save(deps.storage, auction_id, &settlement)?;
+    auction.total_raised = auction.uniform_proceeds(clearing_price, &settlement)?;
+    AUCTIONS.save(deps.storage, auction_id, &auction)?;
+
+    BIDS.update(
+        deps.storage,
+        (auction_id, &info.sender),
+        |position| -> StdResult<_> {
+            let mut position = position.unwrap_or_default();
+            position.total_paid = position.total_paid.checked_sub(refund_amount)?;
+            position.total_acquired = position.total_acquired.checked_add(acquired_amount)?;
+            if !auction.defers_delivery() {
+                position.claimed_amount = position.total_acquired;
+            }
+            Ok(position)
+        },
+    )?;
+
+    let mut msgs: Vec<CosmosMsg> = vec![];
+    if !acquired_amount.is_zero() && !auction.defers_delivery() {
+        msgs.push(
+            auction
+                .offered_asset
+                .info
+                .transfer_msg(&info.sender, acquired_amount)?,
+        );
+    }
+    if !refund_amount.is_zero() {
+        msgs.push(auction.in_asset.transfer_msg(&info.sender, refund_amount)?);
+    }
+    // The last settlement returns the tokens the rounding left unsold
+    let mut returned_amount = Uint128::zero();
+    if settlement.open_commitments == 0 {
+        returned_amount = auction
+            .uniform_sold_amount(clearing_price)?
+            .saturating_sub(settlement.settled_amount);
+    }
+    if !returned_amount.is_zero() {
+        msgs.push(
+            auction
+                .offered_asset
+                .info
+                .transfer_msg(&auction.creator, returned_amount)?,
+        );
+    }
+
+    let res: Response = Response::default()
+        .add_attribute("action", "claim_settlement")
+        .add_attribute("auction_id", auction_id.to_string())
+        .add_attribute("bidder", info.sender)
+        .add_attribute("clearing_price", clearing_price.to_string())
+        .add_attribute("acquired_asset_amount", acquired_amount.to_string())
+        .add_attribute("refunded_amount", refund_amount.to_string())
+        .add_attribute("returned_amount", returned_amount.to_string())
+        .add_messages(msgs);
+    Ok(res)
+}
+
+fn execute_claim_tokens(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    auction_id: u64,
+) -> Result<Response, ContractError> {
+    let auction = AUCTIONS
+        .load(deps.storage, auction_id)
+        .map_err(|_| ContractError::AuctionNotFound {})?;
+
+    // Escrowed tokens wait for the minimum raise to be confirmed
+    if auction.is_escrowed()
+        && matches!(auction.status, AuctionStatus::Open | AuctionStatus::SoldOut)
+    {
+        return Err(ContractError::AuctionNotEnded {});
+    }
+
+    let mut position = BIDS
+        .may_load(deps.storage, (auction_id, &info.sender))?
+        .unwrap_or_default();
+    let unlocked = auction.unlocked_amount(position.total_acquired, env.block.time);
+    let claimable = unlocked.saturating_sub(position.claimed_amount);
+    if claimable.is_zero() {
+        return Err(ContractError::NoTokensToClaim {});
+    }
+    position.claimed_amount = unlocked;
+    BIDS.save(deps.storage, (auction_id, &info.sender), &position)?;
+
+    let msg = auction
+        .offered_asset
+        .info
+        .transfer_msg(&info.sender, claimable)?;
+
+    let res: Response = Response::default()
+        .add_attribute("action", "claim_tokens")
+        .add_attribute("auction_id", auction_id.to_string())
+        .add_attribute("bidder", info.sender)
+        .add_attribute(
+            "claimed_asset_denom",
+            auction.offered_asset.info.to_string(),
+        )
+        .add_attribute("claimed_asset_amount", claimable.to_string())
+        .add_message(msg);
+    Ok(res)
+}
+
+fn execute_claim_refund(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    auction_id: u64,
+) -> Result<Response, ContractError> {
+    let auction = AUCTIONS
+        .load(deps.storage, auction_id)
+        .map_err(|_| ContractError::AuctionNotFound {})?;
+
+    if auction.status != AuctionStatus::Failed {
+        return Err(ContractError::AuctionNotFailed {});
+    }
+
+    // Uniform price commitments are tracked in the position as well
+    let mut position = BIDS
+        .may_load(deps.storage, (auction_id, &info.sender))?
+        .unwrap_or_default();
+    let refund_amount = position.total_paid;
+    if refund_amount.is_zero() {
+        return Err(ContractError::NoRefundToClaim {});
+    }
+    position.total_paid = Uint128::zero();
+    position.total_acquired = Uint128::zero();
+    BIDS.save(deps.storage, (auction_id, &info.sender), &position)?;
+    COMMITMENTS.remove(deps.storage, (auction_id, &info.sender));
+
+    let msg = auction.in_asset.transfer_msg(&info.sender, refund_amount)?;
+
+    let res: Response = Response::default()
+        .add_attribute("action", "claim_refund")
+        .add_attribute("auction_id", auction_id.to_string())
+        .add_attribute("bidder", info.sender)
+        .add_attribute("refunded_denom", auction.in_denom)
+        .add_attribute("refunded_amount", refund_amount.to_string())
+        .add_message(msg);
+    Ok(res)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    let stored = cw2::get_contract_version(deps.storage)?;
+    if stored.contract != CONTRACT_NAME {
+        return Err(ContractError::InvalidContractName {
+            contract: stored.contract,
+        });
+    }
+
+    let stored_version: Version = stored.version.parse()?;
+    let new_version: Version = CONTRACT_VERSION.parse()?;
+    if stored_version > new_version {
+        return Err(ContractError::CannotMigrateToOlderVersion {
+            stored_version: stored.version,
+            new_version: CONTRACT_VERSION.to_string(),
+        });
+    }
+
+    // Bid payments of v0.1 auctions can no longer be told apart per auction,
+    // the migration names who pays them out to the creators
+    let mut msgs: Vec<CosmosMsg> = vec![];
+    if stored_version < Version::new(0, 2, 0) {
+        let proceeds = v0_1::unbooked_proceeds(deps.storage, &deps.querier, &env.contract.address)?;
+        if !proceeds.is_empty() {
+            let recipient = msg.legacy_proceeds_recipient.ok_or_else(|| {
+                let proceeds: Vec<String> = proceeds.iter().map(Coin::to_string).collect();
+                ContractError::LegacyProceedsRecipientRequired {
+                    proceeds: proceeds.join(","),
+                }
+            })?;
+            let recipient = deps.api.addr_validate(&recipient)?;
+            msgs.push(
+                BankMsg::Send {
+                    to_address: recipient.to_string(),
+                    amount: proceeds,
+                }
+                .into(),
+            );
+        }
+    }
+
+    migrate_state(deps.storage, &stored_version)?;
+    cw2::set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let res: Response = Response::default()
+        .add_attribute("action", "migrate")
+        .add_attribute("from_version", stored.version)
+        .add_attribute("to_version", CONTRACT_VERSION)
+        .add_messages(msgs);
     Ok(res)
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(_deps: Deps, _env: Env, _msg: QueryMsg) -> StdResult<Binary> {
-    match _msg {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
         QueryMsg::Auctions { start_after, limit } => {
-            to_json_binary(&query_auctions(_deps, start_after, limit)?)
+            to_json_binary(&query_auctions(deps, start_after, limit)?)
         }
 
-        QueryMsg::Auction { auction_id } => to_json_binary(&query_auction(_deps, auction_id)?),
-        QueryMsg::Params {} => to_json_binary(&query_params(_deps)?),
+        QueryMsg::Auction { auction_id } => to_json_binary(&query_auction(deps, auction_id)?),
+        QueryMsg::Params {} => to_json_binary(&query_params(deps)?),
+        QueryMsg::CurrentPrice { auction_id } => {
+            to_json_binary(&query_current_price(deps, env, auction_id)?)
+        }
+        QueryMsg::Bids {
+            auction_id,
+            start_after,
+            limit,
+        } => to_json_binary(&query_bids(deps, auction_id, start_after, limit)?),
+        QueryMsg::BidderPosition { auction_id, bidder } => {
+            to_json_binary(&query_bidder_position(deps, auction_id, bidder)?)
+        }
+        QueryMsg::Allowlist {
+            auction_id,
+            start_after,
+            limit,
+        } => to_json_binary(&query_allowlist(deps, auction_id, start_after, limit)?),
+        QueryMsg::SimulateBid {
+            auction_id,
+            amount,
+            at_time,
+        } => to_json_binary(&query_simulate_bid(deps, env, auction_id, amount, at_time)?),
+        QueryMsg::Claimable { auction_id, bidder } => {
+            to_json_binary(&query_claimable(deps, env, auction_id, bidder)?)
+        }
+        QueryMsg::Proceeds { auction_id } => {
+            to_json_binary(&query_proceeds(deps, env, auction_id)?)
+        }
     }
 }
 
@@ -283,19 +1247,80 @@ fn query_params(deps: Deps) -> Result<Params, ContractError> {
         .map_err(|_| ContractError::InvalidParams {})
 }
 
-fn query_auction(deps: Deps, auction_id: u8) -> Result<Auction, ContractError> {
+fn query_auction(deps: Deps, auction_id: u64) -> Result<Auction, ContractError> {
     AUCTIONS
         .load(deps.storage, auction_id)
         .map_err(|_| ContractError::AuctionNotFound {})
 }
 
+fn query_current_price(
+    deps: Deps,
+    env: Env,
+    auction_id: u64,
+) -> Result<CurrentPriceResponse, ContractError> {
+    let auction = query_auction(deps, auction_id)?;
+    let now = env.block.time;
+
+    Ok(CurrentPriceResponse {
+        price: auction.calculate_price(now),
+        phase: auction.phase(now),
+        seconds_until_start: auction.start_time.seconds().saturating_sub(now.seconds()),
+        seconds_until_end: auction.end_time.seconds().saturating_sub(now.seconds()),
+        remaining_amount: auction.remaining_amount,
+        next_price_change: auction.next_price_change(now),
+    })
+}
+
+fn query_simulate_bid(
+    deps: Deps,
+    env: Env,
+    auction_id: u64,
+    amount: Coin,
+    at_time: Option<Timestamp>,
+) -> Result<SimulateBidResponse, ContractError> {
+    let auction = query_auction(deps, auction_id)?;
+    let params = PARAMS.load(deps.storage)?;
+    let at_time = at_time.unwrap_or(env.block.time);
+
+    // `amount.denom` may also name a CW20 bidding token
+    let payment = if amount.denom == auction.in_denom {
+        auction.in_asset.clone()
+    } else {
+        AssetInfo::Native {
+            denom: amount.denom.clone(),
+        }
+    };
+    let outcome = match auction.payment_rate(&payment) {
+        Some(rate) => auction.quote_payment(amount.amount, rate, at_time, &params),
+        None => Err(PaymentError::MissingDenom(auction.in_denom.clone()).into()),
+    };
+
+    let res = match outcome {
+        Ok(outcome) => SimulateBidResponse {
+            price: outcome.price,
+            acquired_amount: outcome.acquired_amount,
+            cost: outcome.cost,
+            refund_amount: outcome.refund_amount,
+            error: None,
+        },
+        Err(err) => SimulateBidResponse {
+            price: auction.calculate_price(at_time),
+            acquired_amount: Uint128::zero(),
+            cost: Uint128::zero(),
+            refund_amount: amount.amount,
+            error: Some(err.to_string()),
+        },
+    };
+    Ok(res)
+}
+
 const MAX_LIMIT: u8 = 30;
 
 fn query_auctions(
     deps: Deps,
-    start_after: Option<u8>,
+    start_after: Option<u64>,
     limit: Option<u8>,
-) -> StdResult<Vec<(u8, Auction)>> {
+) -> StdResult<Vec<(u64, Auction)>> {
     let start = start_after.map(Bound::exclusive);
     let limit = limit.unwrap_or(MAX_LIMIT).min(MAX_LIMIT) as usize;
 
@@ -310,5 +1335,104 @@ fn query_auctions(
     Ok(auctions)
 }
 
+fn query_bids(
+    deps: Deps,
+    auction_id: u64,
+    start_after: Option<String>,
+    limit: Option<u8>,
+) -> StdResult<Vec<(Addr, BidderPosition)>> {
+    let start_after = start_after
+        .map(|bidder| deps.api.addr_validate(&bidder))
+        .transpose()?;
+    let start = start_after.as_ref().map(Bound::exclusive);
+    let limit = limit.unwrap_or(MAX_LIMIT).min(MAX_LIMIT) as usize;
+
+    BIDS.prefix(auction_id)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect()
+}
+
+fn query_bidder_position(deps: Deps, auction_id: u64, bidder: String) -> StdResult<BidderPosition> {
+    let bidder = deps.api.addr_validate(&bidder)?;
+    Ok(BIDS
+        .may_load(deps.storage, (auction_id, &bidder))?
+        .unwrap_or_default())
+}
+
+fn query_claimable(
+    deps: Deps,
+    env: Env,
+    auction_id: u64,
+    bidder: String,
+) -> Result<ClaimableResponse, ContractError> {
+    let auction = query_auction(deps, auction_id)?;
+    let position = query_bidder_position(deps, auction_id, bidder)?;
+    let unlocked_amount = auction.unlocked_amount(position.total_acquired, env.block.time);
+
+    Ok(ClaimableResponse {
+        total_amount: position.total_acquired,
+        unlocked_amount,
+        claimed_amount: position.claimed_amount,
+        claimable_amount: unlocked_amount.saturating_sub(position.claimed_amount),
+        locked_amount: position.total_acquired.saturating_sub(unlocked_amount),
+    })
+}
+
+fn query_proceeds(
+    deps: Deps,
+    env: Env,
+    auction_id: u64,
+) -> Result<ProceedsResponse, ContractError> {
+    let auction = query_auction(deps, auction_id)?;
+    let vested_amount = auction.vested_proceeds(env.block.time);
+    let quote_proceeds = auction
+        .quote_denoms
+        .iter()
+        .map(|quote| -> StdResult<_> {
+            let proceeds = QUOTE_PROCEEDS
+                .may_load(deps.storage, (auction_id, &quote.denom))?
+                .unwrap_or_default();
+            let released = auction.released_amount(proceeds.total_raised, env.block.time);
+            Ok(DenomProceeds {
+                denom: quote.denom.clone(),
+                total_raised: proceeds.total_raised,
+                claimed_amount: proceeds.total_claimed,
+                claimable_amount: released.saturating_sub(proceeds.total_claimed),
+                locked_amount: proceeds.total_raised.saturating_sub(released),
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(ProceedsResponse {
+        total_raised: auction.total_raised,
+        vested_amount,
+        claimed_amount: auction.total_claimed,
+        claimable_amount: vested_amount.saturating_sub(auction.total_claimed),
+        locked_amount: auction.total_raised.saturating_sub(vested_amount),
+        vesting: auction.proceeds_vesting.clone(),
+        quote_proceeds,
+    })
+}
+
+fn query_allowlist(
+    deps: Deps,
+    auction_id: u64,
+    start_after: Option<String>,
+    limit: Option<u8>,
+) -> StdResult<Vec<Addr>> {
+    let start_after = start_after
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
+    let start = start_after.as_ref().map(Bound::exclusive);
+    let limit = limit.unwrap_or(MAX_LIMIT).min(MAX_LIMIT) as usize;
+
+    ALLOWLIST
+        .prefix(auction_id)
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {}
@@ -1,9 +1,11 @@
 use crate::helpers::CustomPaymentError;
 use cosmwasm_std::CheckedFromRatioError;
+use cosmwasm_std::Decimal;
 use cosmwasm_std::DivideByZeroError;
 use cosmwasm_std::OverflowError;
 use cosmwasm_std::StdError;
 use cosmwasm_std::Timestamp;
+use cosmwasm_std::Uint128;
 use cw_utils::PaymentError;
 use thiserror::Error;
 
@@ -26,6 +28,9 @@ pub enum ContractError {
     #[error("{0}")]
     Overflow(#[from] OverflowError),
 
+    #[error("{0}")]
+    SemVer(#[from] semver::Error),
+
     #[error("Unauthorized: the sender is not authorized to perform this action")]
     Unauthorized {},
 
@@ -35,6 +40,9 @@ pub enum ContractError {
         end_time: Timestamp,
     },
 
+    #[error("Offered amount must be greater than zero")]
+    ZeroOfferedAmount {},
+
     #[error("End price can not be higher than starting price")]
     EndPriceHigherThanStartingPrice {},
 
@@ -56,11 +64,122 @@ pub enum ContractError {
     #[error("Offered asset and input denom cannot be the same (denom: {denom})")]
     SameDenomination { denom: String },
 
+    #[error("Bidding denom is not accepted (denom: {denom})")]
+    InDenomNotAccepted { denom: String },
+
+    #[error("Starting price ({starting_price}) is below the minimum for the bidding denom ({min_starting_price})")]
+    StartingPriceTooLow {
+        starting_price: Decimal,
+        min_starting_price: Decimal,
+    },
+
+    #[error("Bid amount ({amount}) is below the minimum bid size ({min_bid_size})")]
+    BidTooSmall {
+        min_bid_size: Uint128,
+        amount: Uint128,
+    },
+
+    #[error("Bid amount is too small to acquire any tokens at the current price ({price})")]
+    BidTooSmallForPrice { price: Decimal },
+
+    #[error("Auction price ({price}) is above the max price ({max_price})")]
+    PriceAboveMaxPrice { price: Decimal, max_price: Decimal },
+
+    #[error("Acquired amount ({acquired_amount}) is below the min amount out ({min_amount_out})")]
+    AmountBelowMinAmountOut {
+        acquired_amount: Uint128,
+        min_amount_out: Uint128,
+    },
+
+    #[error("Invalid price curve: {reason}")]
+    InvalidPriceCurve { reason: String },
+
+    #[error("Step interval ({step_interval} seconds) must evenly divide the auction duration ({duration} seconds)")]
+    InvalidStepInterval { step_interval: u64, duration: u64 },
+
+    #[error("No commitment found for the sender")]
+    NoCommitment {},
+
+    #[error("Auction has not been settled yet")]
+    AuctionNotSettled {},
+
+    #[error("Bid amount ({amount}) exceeds the maximum bid size ({max_bid_size})")]
+    BidTooLarge {
+        max_bid_size: Uint128,
+        amount: Uint128,
+    },
+
+    #[error("Purchase would bring the wallet to {total_acquired} tokens, above the cap of {max_per_wallet}")]
+    WalletCapExceeded {
+        max_per_wallet: Uint128,
+        total_acquired: Uint128,
+    },
+
+    #[error("Purchase caps must be greater than zero")]
+    InvalidPurchaseCap {},
+
+    #[error("Per-wallet caps and allocations are only supported by pay-as-bid auctions")]
+    WalletCapRequiresPayAsBid {},
+
+    #[error("Sender is not on the auction's allowlist")]
+    NotAllowlisted {},
+
+    #[error("Auction is not allowlist-only")]
+    AllowlistNotEnabled {},
+
+    #[error("Creation fee must be deposited before creating a CW20 funded auction")]
+    CreationFeeNotDeposited {},
+
+    #[error("Creation fee is already deposited")]
+    CreationFeeAlreadyDeposited {},
+
+    #[error("Minimum raise must be greater than zero")]
+    InvalidMinRaise {},
+
+    #[error("Vesting cliff must not exceed a non-zero duration")]
+    InvalidVestingSchedule {},
+
+    #[error("Tokens or proceeds would stay locked {duration} seconds after the auction end, above the maximum of {max_duration} seconds")]
+    VestingTooLong { duration: u64, max_duration: u64 },
+
+    #[error("Claim start must not be before the auction end")]
+    InvalidClaimStart {},
+
+    #[error("Auction did not fail to reach its minimum raise")]
+    AuctionNotFailed {},
+
+    #[error("No tokens to claim")]
+    NoTokensToClaim {},
+
+    #[error("No refund to claim")]
+    NoRefundToClaim {},
+
+    #[error("Invalid quote denom {denom}")]
+    InvalidQuoteDenom { denom: String },
+
+    #[error("Quote denoms are only supported by pay-as-bid auctions without a minimum raise")]
+    QuoteDenomsRequirePayAsBid {},
+
+    #[error("Merkle root must be 32 bytes")]
+    InvalidMerkleRoot {},
+
+    #[error("Auction requires a merkle proof of the sender's allocation")]
+    MissingMerkleProof {},
+
+    #[error("Invalid merkle proof")]
+    InvalidMerkleProof {},
+
+    #[error("Purchase would bring the wallet to {total_acquired} tokens, above its allocation of {allocation}")]
+    AllocationExceeded {
+        allocation: Uint128,
+        total_acquired: Uint128,
+    },
+
     #[error("Invalid params")]
     InvalidParams {},
 
-    #[error("Auction remaining amount is insufficient")]
-    InsufficientRemainingAmount {},
+    #[error("Auction is sold out")]
+    AuctionSoldOut {},
 
     #[error("Auction not found")]
     AuctionNotFound {},
@@ -70,6 +189,32 @@ pub enum ContractError {
 
     #[error("Auction cannot be canceled")]
     AuctionCannotBeCanceled {},
+
+    #[error("No proceeds to claim")]
+    NoProceedsToClaim {},
+
+    #[error("Auction has not ended yet")]
+    AuctionNotEnded {},
+
+    #[error("Auction is already finalized")]
+    AuctionAlreadyFinalized {},
+
+    #[error("Auction is cancelled")]
+    AuctionCancelled {},
+
+    #[error("Unbooked v0.1 bid payments ({proceeds}) need a legacy proceeds recipient")]
+    LegacyProceedsRecipientRequired { proceeds: String },
+
+    #[error("Cannot migrate from a different contract ({contract})")]
+    InvalidContractName { contract: String },
+
+    #[error(
+        "Cannot migrate from newer version ({stored_version}) to older version ({new_version})"
+    )]
+    CannotMigrateToOlderVersion {
+        stored_version: String,
+        new_version: String,
+    },
 }
 
 impl From<ContractError> for StdError {
@@ -1,9 +1,16 @@
+pub mod asset;
 pub mod auction;
 pub mod contract;
 mod error;
 pub mod helpers;
+pub mod merkle;
+pub mod migrations;
 pub mod msg;
+pub mod price_curve;
 pub mod state;
 pub mod test;
+#[cfg(feature = "tokenfactory")]
+pub mod token_factory;
+pub mod vesting;
 
 pub use crate::error::ContractError;
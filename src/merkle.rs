@@ -0,0 +1,133 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{HexBinary, Uint128};
+use sha2::{Digest, Sha256};
+
+pub type Hash = [u8; 32];
+
+/// Proof that `address` holds `allocation` in a merkle allowlist.
+#[cw_serde]
+pub struct MerkleProof {
+    /// Most offered tokens the address may buy
+    pub allocation: Uint128,
+    /// Sibling hashes from the leaf up to the root
+    pub proof: Vec<HexBinary>,
+}
+
+/// Leaf of an allowlist entry, `sha256("{address}:{allocation}")`.
+pub fn leaf_hash(address: &str, allocation: Uint128) -> Hash {
+    Sha256::digest(format!("{address}:{allocation}")).into()
+}
+
+/// Hashes two nodes in sorted order, so proofs do not need to encode sides.
+fn hash_pair(a: &Hash, b: &Hash) -> Hash {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    let mut hasher = Sha256::new();
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// Checks `proof` leads from the leaf of `address` and `allocation` to `root`.
+pub fn verify(root: &[u8], address: &str, allocation: Uint128, proof: &[HexBinary]) -> bool {
+    let mut hash = leaf_hash(address, allocation);
+    for sibling in proof {
+        let Ok(sibling) = Hash::try_from(sibling.as_slice()) else {
+            return false;
+        };
+        hash = hash_pair(&hash, &sibling);
+    }
+    hash.as_slice() == root
+}
+
+/// Merkle tree over `(address, allocation)` leaves, used to publish a root and
+/// hand out proofs. An unpaired node is carried up to the next level as is.
+pub struct MerkleTree {
+    levels: Vec<Vec<Hash>>,
+}
+
+impl MerkleTree {
+    pub fn new(entries: &[(String, Uint128)]) -> Self {
+        let leaves: Vec<Hash> = entries
+            .iter()
+            .map(|(address, allocation)| leaf_hash(address, *allocation))
+            .collect();
+
+        let mut levels = vec![leaves];
+        while levels.last().is_some_and(|level| level.len() > 1) {
+            let next = levels
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => hash_pair(left, right),
+                    [single] => *single,
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(next);
+        }
+        MerkleTree { levels }
+    }
+
+    pub fn root(&self) -> HexBinary {
+        self.levels
+            .last()
+            .and_then(|level| level.first())
+            .map(|root| HexBinary::from(root.as_slice()))
+            .unwrap_or_default()
+    }
+
+    /// Proof for the entry at `index` in the list the tree was built from.
+    pub fn proof(&self, mut index: usize) -> Vec<HexBinary> {
+        let mut proof = vec![];
+        for level in &self.levels[..self.levels.len() - 1] {
+            if let Some(sibling) = level.get(index ^ 1) {
+                proof.push(HexBinary::from(sibling.as_slice()));
+            }
+            index /= 2;
+        }
+        proof
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entries(count: u128) -> Vec<(String, Uint128)> {
+        (0..count)
+            .map(|i| (format!("address{i}"), Uint128::new(100 + i)))
+            .collect()
+    }
+
+    #[test]
+    fn test_proofs_verify_against_root() {
+        for count in [1, 2, 3, 7, 8, 13] {
+            let entries = entries(count);
+            let tree = MerkleTree::new(&entries);
+            let root = tree.root();
+
+            for (index, (address, allocation)) in entries.iter().enumerate() {
+                let proof = tree.proof(index);
+                assert!(verify(&root, address, *allocation, &proof));
+                // A different allocation or address does not verify
+                assert!(!verify(
+                    &root,
+                    address,
+                    *allocation + Uint128::one(),
+                    &proof
+                ));
+                assert!(!verify(&root, "intruder", *allocation, &proof));
+            }
+        }
+    }
+
+    #[test]
+    fn test_malformed_proof_is_rejected() {
+        let entries = entries(4);
+        let tree = MerkleTree::new(&entries);
+        let mut proof = tree.proof(0);
+        proof[0] = HexBinary::from(&[1u8, 2, 3][..]);
+        assert!(!verify(&tree.root(), &entries[0].0, entries[0].1, &proof));
+    }
+}
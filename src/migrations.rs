@@ -0,0 +1,239 @@
+use cosmwasm_std::{Addr, Coin, Order, QuerierWrapper, StdResult, Storage, Uint128};
+use semver::Version;
+
+use crate::asset::AssetInfo;
+use crate::auction::{Auction, AuctionStatus, SettlementMode, TokenDelivery};
+use crate::price_curve::PriceCurve;
+use crate::state::{
+    AcceptedDenom, Params, AUCTIONS, AUCTION_INDEX, DEFAULT_MAX_VESTING_DURATION, PARAMS,
+};
+use crate::ContractError;
+
+/// Runs every state transformation needed to bring storage written by
+/// `stored_version` up to the current layout.
+pub fn migrate_state(
+    storage: &mut dyn Storage,
+    stored_version: &Version,
+) -> Result<(), ContractError> {
+    if *stored_version < Version::new(0, 2, 0) {
+        v0_1::migrate(storage)?;
+    }
+    Ok(())
+}
+
+/// State layout of v0.1.x, where auctions were keyed by u8 and carried no
+/// proceeds or status bookkeeping.
+pub(crate) mod v0_1 {
+    use cosmwasm_schema::cw_serde;
+    use cosmwasm_std::{Decimal, Timestamp};
+    use cw_storage_plus::{Item, Map};
+
+    use super::*;
+
+    #[cw_serde]
+    pub struct Auction {
+        pub creator: String,
+        pub offered_asset: Coin,
+        pub in_denom: String,
+        pub starting_price: Decimal,
+        pub end_price: Decimal,
+        pub start_time: Timestamp,
+        pub end_time: Timestamp,
+        pub remaining_amount: Uint128,
+    }
+
+    #[cw_serde]
+    pub struct Params {
+        pub auction_creation_fee: Coin,
+        pub admin: Addr,
+        pub min_seconds_until_auction_start: u64,
+        pub max_auction_duration: u64,
+        pub accepted_denoms: Vec<String>,
+    }
+
+    pub const AUCTIONS: Map<u8, Auction> = Map::new("auctions");
+    pub const AUCTION_INDEX: Item<u8> = Item::new("auction_index");
+    pub const PARAMS: Item<Params> = Item::new("params");
+
+    pub fn migrate(storage: &mut dyn Storage) -> StdResult<()> {
+        let params = PARAMS.load(storage)?;
+        super::PARAMS.save(
+            storage,
+            &super::Params {
+                auction_creation_fee: params.auction_creation_fee,
+                admin: params.admin,
+                min_seconds_until_auction_start: params.min_seconds_until_auction_start,
+                max_auction_duration: params.max_auction_duration,
+                accepted_denoms: params
+                    .accepted_denoms
+                    .into_iter()
+                    .map(|denom| AcceptedDenom {
+                        denom,
+                        min_starting_price: None,
+                        min_bid_size: None,
+                    })
+                    .collect(),
+                cancellation_fee_refund: Decimal::zero(),
+                max_vesting_duration: DEFAULT_MAX_VESTING_DURATION,
+            },
+        )?;
+
+        let legacy_auctions = AUCTIONS
+            .range(storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        for (auction_id, auction) in legacy_auctions {
+            AUCTIONS.remove(storage, auction_id);
+            super::AUCTIONS.save(storage, auction_id as u64, &auction.into())?;
+        }
+
+        let index = AUCTION_INDEX.may_load(storage)?.unwrap_or_default();
+        super::AUCTION_INDEX.save(storage, &(index as u64))?;
+
+        Ok(())
+    }
+
+    /// Bid payments held by the contract, per in_denom of the legacy auctions.
+    /// v0.1 kept them without booking them per auction, so only the balance
+    /// left after the offered tokens still escrowed is known.
+    pub fn unbooked_proceeds(
+        storage: &dyn Storage,
+        querier: &QuerierWrapper,
+        contract: &Addr,
+    ) -> StdResult<Vec<Coin>> {
+        let auctions = AUCTIONS
+            .range(storage, None, None, Order::Ascending)
+            .map(|item| item.map(|(_, auction)| auction))
+            .collect::<StdResult<Vec<_>>>()?;
+        let mut in_denoms: Vec<&str> = auctions
+            .iter()
+            .map(|auction| auction.in_denom.as_str())
+            .collect();
+        in_denoms.sort_unstable();
+        in_denoms.dedup();
+
+        let mut proceeds = vec![];
+        for denom in in_denoms {
+            let balance = querier.query_balance(contract, denom)?.amount;
+            let escrowed: Uint128 = auctions
+                .iter()
+                .filter(|auction| auction.offered_asset.denom == denom)
+                .map(|auction| auction.remaining_amount)
+                .sum();
+            let amount = balance.saturating_sub(escrowed);
+            if !amount.is_zero() {
+                proceeds.push(Coin {
+                    denom: denom.to_string(),
+                    amount,
+                });
+            }
+        }
+        Ok(proceeds)
+    }
+
+    impl From<Auction> for super::Auction {
+        // Proceeds of bids made before the upgrade were never accounted for, so
+        // legacy auctions start with empty proceeds bookkeeping. The payments
+        // themselves are paid out on migration, see `unbooked_proceeds`.
+        fn from(auction: Auction) -> Self {
+            super::Auction {
+                creator: auction.creator,
+                offered_asset: auction.offered_asset.into(),
+                in_asset: AssetInfo::Native {
+                    denom: auction.in_denom.clone(),
+                },
+                in_denom: auction.in_denom,
+                starting_price: auction.starting_price,
+                end_price: auction.end_price,
+                start_time: auction.start_time,
+                end_time: auction.end_time,
+                remaining_amount: auction.remaining_amount,
+                total_raised: Uint128::zero(),
+                total_claimed: Uint128::zero(),
+                status: AuctionStatus::Open,
+                held_creation_fee: None,
+                price_curve: PriceCurve::Linear,
+                step_interval: None,
+                settlement_mode: SettlementMode::PayAsBid,
+                total_committed: Uint128::zero(),
+                clearing_price: None,
+                max_per_wallet: None,
+                max_bid_size: None,
+                allowlist_only: false,
+                merkle_root: None,
+                min_raise: None,
+                token_delivery: TokenDelivery::Instant,
+                proceeds_vesting: None,
+                quote_denoms: vec![],
+                mint_offered: false,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use cosmwasm_std::testing::MockStorage;
+    use cosmwasm_std::{coin, Addr, Decimal, Timestamp, Uint128};
+
+    #[test]
+    fn test_migrate_from_v0_1() {
+        let mut storage = MockStorage::new();
+        let legacy_auction = v0_1::Auction {
+            creator: "creator".to_string(),
+            offered_asset: coin(1000, "ubtc"),
+            in_denom: "uusd".to_string(),
+            starting_price: Decimal::from_str("100").unwrap(),
+            end_price: Decimal::from_str("10").unwrap(),
+            start_time: Timestamp::from_seconds(1000),
+            end_time: Timestamp::from_seconds(2000),
+            remaining_amount: Uint128::new(400),
+        };
+        for auction_id in [1u8, 2, 255] {
+            v0_1::AUCTIONS
+                .save(&mut storage, auction_id, &legacy_auction)
+                .unwrap();
+        }
+        v0_1::AUCTION_INDEX.save(&mut storage, &255).unwrap();
+        v0_1::PARAMS
+            .save(
+                &mut storage,
+                &v0_1::Params {
+                    auction_creation_fee: coin(100, "uusd"),
+                    admin: Addr::unchecked("admin"),
+                    min_seconds_until_auction_start: 10,
+                    max_auction_duration: 1000,
+                    accepted_denoms: vec!["uusd".to_string()],
+                },
+            )
+            .unwrap();
+
+        migrate_state(&mut storage, &Version::new(0, 1, 0)).unwrap();
+
+        let auctions = AUCTIONS
+            .range(&storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        let auction: Auction = legacy_auction.into();
+        assert_eq!(auction.remaining_amount, Uint128::new(400));
+        assert_eq!(auction.status, AuctionStatus::Open);
+        assert_eq!(
+            auctions,
+            vec![(1, auction.clone()), (2, auction.clone()), (255, auction)]
+        );
+        assert_eq!(AUCTION_INDEX.load(&storage).unwrap(), 255);
+        let params = PARAMS.load(&storage).unwrap();
+        assert_eq!(params.cancellation_fee_refund, Decimal::zero());
+        assert_eq!(params.max_vesting_duration, DEFAULT_MAX_VESTING_DURATION);
+        assert_eq!(
+            params.accepted_denoms,
+            vec![AcceptedDenom {
+                denom: "uusd".to_string(),
+                min_starting_price: None,
+                min_bid_size: None,
+            }]
+        );
+    }
+}
@@ -1,7 +1,14 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Coin, Decimal, Timestamp};
+use cosmwasm_std::{Addr, Coin, Decimal, HexBinary, Timestamp, Uint128};
+use cw20::Cw20ReceiveMsg;
 
-use crate::{auction::Auction, state::Params};
+use crate::{
+    auction::{Auction, AuctionPhase, QuoteDenom, SettlementMode, TokenDelivery},
+    merkle::MerkleProof,
+    price_curve::PriceCurve,
+    state::{AcceptedDenom, BidderPosition, Params},
+    vesting::VestingSchedule,
+};
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -9,45 +16,257 @@ pub struct InstantiateMsg {
     pub admin: String,
     pub min_seconds_until_auction_start: u64,
     pub max_auction_duration: u64,
-    pub accepted_denoms: Vec<String>,
+    pub accepted_denoms: Vec<AcceptedDenom>,
+    pub cancellation_fee_refund: Option<Decimal>,
+    /// Longest bought tokens or proceeds may stay locked after an auction
+    /// ends, in seconds. Defaults to four years.
+    pub max_vesting_duration: Option<u64>,
 }
 
+/// Settings of a new auction, shared by every way of creating one.
 #[cw_serde]
+pub struct AuctionOptions {
+    /// Native denom or CW20 contract address bids are paid in
+    pub in_denom: String,
+    pub starting_price: Decimal,
+    pub end_price: Decimal,
+    pub start_time: Timestamp,
+    pub end_time: Timestamp,
+    /// Defaults to a linear price decay
+    pub price_curve: Option<PriceCurve>,
+    /// Seconds between price ticks, the price moves continuously if unset
+    pub step_interval: Option<u64>,
+    /// Defaults to pay-as-bid
+    pub settlement_mode: Option<SettlementMode>,
+    /// Most offered tokens a single wallet may buy
+    pub max_per_wallet: Option<Uint128>,
+    /// Largest payment accepted in a single bid
+    pub max_bid_size: Option<Uint128>,
+    /// Only addresses added to the allowlist by the creator may bid
+    pub allowlist_only: Option<bool>,
+    /// Merkle root of `(address, allocation)` leaves, bidders must prove
+    /// their allocation and cannot buy more than it
+    pub merkle_root: Option<HexBinary>,
+    /// Proceeds the auction must raise by its end, otherwise bidders are
+    /// refunded. Bought tokens are held until finalization.
+    pub min_raise: Option<Uint128>,
+    /// Defaults to sending bought tokens right away
+    pub token_delivery: Option<TokenDelivery>,
+    /// Releases the proceeds to the creator gradually after the auction end
+    pub proceeds_vesting: Option<VestingSchedule>,
+    /// Other native denoms bids may be paid in, at a fixed rate to `in_denom`
+    pub quote_denoms: Option<Vec<QuoteDenom>>,
+}
+
+#[cw_serde]
+#[allow(clippy::large_enum_variant)]
 pub enum ExecuteMsg {
     CreateAuction {
         offered_asset: Coin,
-        in_denom: String,
-        starting_price: Decimal,
-        end_price: Decimal,
-        start_time: Timestamp,
-        end_time: Timestamp,
+        #[serde(flatten)]
+        options: AuctionOptions,
+    },
+    /// Creates or bids in an auction with a CW20 transfer, see `Cw20HookMsg`
+    Receive(Cw20ReceiveMsg),
+    /// Pays the creation fee ahead of a CW20 funded auction
+    DepositCreationFee {},
+    /// Takes back an unused creation fee deposit
+    WithdrawCreationFee {},
+    /// Same as `CreateAuction`, offering `supply` of a new token factory denom
+    /// minted as it is sold. The creator becomes its admin once the auction ends.
+    #[cfg(feature = "tokenfactory")]
+    CreateTokenAuction {
+        subdenom: String,
+        supply: Uint128,
+        #[serde(flatten)]
+        options: AuctionOptions,
     },
     Bid {
-        auction_id: u8,
+        auction_id: u64,
+        /// Fail the bid if the auction price is above this
+        max_price: Option<Decimal>,
+        /// Fail the bid if it would acquire fewer tokens than this
+        min_amount_out: Option<Uint128>,
+        /// Required by auctions with a merkle root
+        merkle_proof: Option<MerkleProof>,
     },
     ChangeParams {
         auction_creation_fee: Option<Coin>,
         min_seconds_until_auction_start: Option<u64>,
         max_auction_duration: Option<u64>,
-        accepted_denoms: Option<Vec<String>>,
+        accepted_denoms: Option<Vec<AcceptedDenom>>,
+        cancellation_fee_refund: Option<Decimal>,
+        max_vesting_duration: Option<u64>,
         admin: Option<String>,
     },
     CancelAuction {
-        auction_id: u8,
+        auction_id: u64,
+    },
+    ClaimProceeds {
+        auction_id: u64,
+    },
+    FinalizeAuction {
+        auction_id: u64,
+    },
+    AddToAllowlist {
+        auction_id: u64,
+        addresses: Vec<String>,
+    },
+    RemoveFromAllowlist {
+        auction_id: u64,
+        addresses: Vec<String>,
+    },
+    /// Claims the tokens and refund of a settled uniform price auction
+    ClaimSettlement {
+        auction_id: u64,
+    },
+    /// Claims the bought tokens held by the auction once they are unlocked
+    ClaimTokens {
+        auction_id: u64,
+    },
+    /// Same as `ClaimTokens`, for auctions vesting the bought tokens
+    ClaimVested {
+        auction_id: u64,
+    },
+    /// Reclaims the payments made to an auction that missed its minimum raise
+    ClaimRefund {
+        auction_id: u64,
     },
 }
 
+/// Messages carried by CW20 transfers to the contract.
+#[cw_serde]
+#[allow(clippy::large_enum_variant)]
+pub enum Cw20HookMsg {
+    /// Same as `ExecuteMsg::CreateAuction`, offering the transferred tokens
+    CreateAuction {
+        #[serde(flatten)]
+        options: AuctionOptions,
+    },
+    /// Same as `ExecuteMsg::Bid`, paying with the transferred tokens
+    Bid {
+        auction_id: u64,
+        max_price: Option<Decimal>,
+        min_amount_out: Option<Uint128>,
+        merkle_proof: Option<MerkleProof>,
+    },
+}
+
+#[cw_serde]
+pub struct MigrateMsg {
+    /// Receives the bid payments a v0.1 contract holds, which were never booked
+    /// per auction. Required when there are any. The payments belong to the
+    /// creators of the v0.1 auctions, the recipient has to pay them out off-chain.
+    pub legacy_proceeds_recipient: Option<String>,
+}
+
 #[cw_serde]
 #[derive(QueryResponses)]
 pub enum QueryMsg {
-    #[returns(Vec<(u8,Auction)>)]
+    #[returns(Vec<(u64, Auction)>)]
     Auctions {
-        start_after: Option<u8>,
+        start_after: Option<u64>,
         limit: Option<u8>,
     },
     #[returns(Auction)]
-    Auction { auction_id: u8 },
+    Auction { auction_id: u64 },
 
     #[returns(Params)]
     Params {},
+
+    /// Price of the auction at the current block time
+    #[returns(CurrentPriceResponse)]
+    CurrentPrice { auction_id: u64 },
+
+    /// Purchase records of an auction's bidders
+    #[returns(Vec<(Addr, BidderPosition)>)]
+    Bids {
+        auction_id: u64,
+        start_after: Option<String>,
+        limit: Option<u8>,
+    },
+
+    #[returns(BidderPosition)]
+    BidderPosition { auction_id: u64, bidder: String },
+
+    #[returns(Vec<Addr>)]
+    Allowlist {
+        auction_id: u64,
+        start_after: Option<String>,
+        limit: Option<u8>,
+    },
+
+    /// Simulates a bid of `amount` at `at_time`, defaulting to the current block time.
+    #[returns(SimulateBidResponse)]
+    SimulateBid {
+        auction_id: u64,
+        amount: Coin,
+        at_time: Option<Timestamp>,
+    },
+
+    /// Bought tokens of a bidder held by the auction
+    #[returns(ClaimableResponse)]
+    Claimable { auction_id: u64, bidder: String },
+
+    /// Proceeds of an auction and their release to the creator
+    #[returns(ProceedsResponse)]
+    Proceeds { auction_id: u64 },
+}
+
+#[cw_serde]
+pub struct CurrentPriceResponse {
+    pub price: Decimal,
+    pub phase: AuctionPhase,
+    pub seconds_until_start: u64,
+    pub seconds_until_end: u64,
+    pub remaining_amount: Uint128,
+    /// Time of the next price tick for stepped auctions
+    pub next_price_change: Option<Timestamp>,
+}
+
+#[cw_serde]
+pub struct SimulateBidResponse {
+    pub price: Decimal,
+    pub acquired_amount: Uint128,
+    pub cost: Uint128,
+    pub refund_amount: Uint128,
+    /// Reason the bid would fail, if any
+    pub error: Option<String>,
+}
+
+#[cw_serde]
+pub struct ClaimableResponse {
+    /// Tokens bought by the bidder
+    pub total_amount: Uint128,
+    /// Tokens unlocked so far, claimed ones included
+    pub unlocked_amount: Uint128,
+    pub claimed_amount: Uint128,
+    /// Tokens that can be claimed right now
+    pub claimable_amount: Uint128,
+    /// Tokens still locked by the delivery schedule
+    pub locked_amount: Uint128,
+}
+
+#[cw_serde]
+pub struct ProceedsResponse {
+    pub total_raised: Uint128,
+    /// Proceeds released to the creator so far, claimed ones included
+    pub vested_amount: Uint128,
+    pub claimed_amount: Uint128,
+    /// Proceeds the creator can claim right now
+    pub claimable_amount: Uint128,
+    /// Proceeds still locked by the vesting schedule
+    pub locked_amount: Uint128,
+    pub vesting: Option<VestingSchedule>,
+    /// Proceeds paid in each quote denom, the amounts above are in `in_denom`
+    pub quote_proceeds: Vec<DenomProceeds>,
+}
+
+#[cw_serde]
+pub struct DenomProceeds {
+    pub denom: String,
+    pub total_raised: Uint128,
+    pub claimed_amount: Uint128,
+    pub claimable_amount: Uint128,
+    pub locked_amount: Uint128,
 }
@@ -0,0 +1,317 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Decimal, Uint128};
+
+use crate::ContractError;
+
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+// Binary digits used to approximate fractional powers of one half
+const FRACTION_PRECISION: u32 = 32;
+
+/// Shape of the price decay between the starting and end price of an auction.
+#[cw_serde]
+#[derive(Default)]
+pub enum PriceCurve {
+    /// Price decreases at a constant rate
+    #[default]
+    Linear,
+    /// Price halves its distance to the end price every `half_life` seconds,
+    /// rescaled so the end price is reached exactly at end time
+    ExponentialDecay { half_life: u64 },
+    /// Price drops by `decrement` every `step_duration` seconds until it
+    /// reaches the end price
+    Stepwise {
+        step_duration: u64,
+        decrement: Decimal,
+    },
+    /// Price moves linearly through `(seconds since start, price)` breakpoints
+    PiecewiseLinear { breakpoints: Vec<(u64, Decimal)> },
+}
+
+impl PriceCurve {
+    pub fn validate(
+        &self,
+        starting_price: Decimal,
+        end_price: Decimal,
+        duration: u64,
+    ) -> Result<(), ContractError> {
+        match self {
+            PriceCurve::Linear => {}
+            PriceCurve::ExponentialDecay { half_life } => {
+                if *half_life == 0 {
+                    return Err(invalid("half life must be greater than zero"));
+                }
+            }
+            PriceCurve::Stepwise {
+                step_duration,
+                decrement,
+            } => {
+                if *step_duration == 0 || *step_duration > duration {
+                    return Err(invalid(
+                        "step duration must be between zero and the auction duration",
+                    ));
+                }
+                if decrement.is_zero() {
+                    return Err(invalid("step decrement must be greater than zero"));
+                }
+            }
+            PriceCurve::PiecewiseLinear { breakpoints } => {
+                let mut previous = (0, starting_price);
+                for &(offset, price) in breakpoints {
+                    if offset <= previous.0 || offset >= duration {
+                        return Err(invalid(
+                            "breakpoints must be strictly increasing and within the auction duration",
+                        ));
+                    }
+                    if price > previous.1 || price < end_price {
+                        return Err(invalid(
+                            "breakpoint prices must be non-increasing and not below the end price",
+                        ));
+                    }
+                    previous = (offset, price);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Price after `time_passed` out of `total_duration` nanoseconds.
+    pub fn price_at(
+        &self,
+        starting_price: Decimal,
+        end_price: Decimal,
+        time_passed: u64,
+        total_duration: u64,
+    ) -> Decimal {
+        if time_passed == 0 {
+            return starting_price;
+        }
+        if time_passed >= total_duration {
+            return end_price;
+        }
+
+        match self {
+            PriceCurve::Linear => {
+                interpolate(starting_price, end_price, time_passed, total_duration)
+            }
+            PriceCurve::ExponentialDecay { half_life } => {
+                let half_life = half_life.saturating_mul(NANOS_PER_SECOND);
+                let now_factor = half_pow(time_passed, half_life);
+                let end_factor = half_pow(total_duration, half_life);
+                // Rescale 0.5^(t/h) from [0.5^(T/h), 1] onto [0, 1]
+                let remaining = now_factor
+                    .saturating_sub(end_factor)
+                    .checked_div(Decimal::one() - end_factor)
+                    .unwrap_or_default();
+                end_price + (starting_price - end_price) * remaining
+            }
+            PriceCurve::Stepwise {
+                step_duration,
+                decrement,
+            } => {
+                let steps = time_passed / step_duration.saturating_mul(NANOS_PER_SECOND);
+                decrement
+                    .checked_mul(Decimal::from_ratio(steps, 1u64))
+                    .ok()
+                    .and_then(|drop| starting_price.checked_sub(drop).ok())
+                    .map_or(end_price, |price| price.max(end_price))
+            }
+            PriceCurve::PiecewiseLinear { breakpoints } => {
+                let mut previous = (0, starting_price);
+                let points = breakpoints
+                    .iter()
+                    .map(|&(offset, price)| (offset.saturating_mul(NANOS_PER_SECOND), price))
+                    .chain([(total_duration, end_price)]);
+                for next in points {
+                    if time_passed < next.0 {
+                        return interpolate(
+                            previous.1,
+                            next.1,
+                            time_passed - previous.0,
+                            next.0 - previous.0,
+                        );
+                    }
+                    previous = next;
+                }
+                end_price
+            }
+        }
+    }
+}
+
+fn invalid(reason: &str) -> ContractError {
+    ContractError::InvalidPriceCurve {
+        reason: reason.to_string(),
+    }
+}
+
+/// Linear interpolation between `from` and `to` after `passed` out of `total`.
+fn interpolate(from: Decimal, to: Decimal, passed: u64, total: u64) -> Decimal {
+    let price_diff = (from - to)
+        .checked_mul(Decimal::from_ratio(
+            Uint128::from(passed as u128),
+            Uint128::from(total as u128),
+        ))
+        .ok();
+    match price_diff {
+        Some(price_diff) => from - price_diff,
+        None => to,
+    }
+}
+
+/// Approximates 0.5^(numerator / denominator).
+fn half_pow(numerator: u64, denominator: u64) -> Decimal {
+    let half = Decimal::percent(50);
+    let whole = numerator / denominator;
+    let mut result = match u32::try_from(whole) {
+        Ok(exp) => half.checked_pow(exp).unwrap_or_default(),
+        Err(_) => return Decimal::zero(),
+    };
+
+    // Each binary digit of the fraction contributes a repeated square root of 0.5
+    let mut remainder = (numerator % denominator) as u128;
+    let denominator = denominator as u128;
+    let mut factor = half.sqrt();
+    for _ in 0..FRACTION_PRECISION {
+        if remainder == 0 {
+            break;
+        }
+        remainder *= 2;
+        if remainder >= denominator {
+            result *= factor;
+            remainder -= denominator;
+        }
+        factor = factor.sqrt();
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    const DURATION: u64 = 1000 * NANOS_PER_SECOND;
+
+    fn dec(value: &str) -> Decimal {
+        Decimal::from_str(value).unwrap()
+    }
+
+    fn curves() -> Vec<PriceCurve> {
+        vec![
+            PriceCurve::Linear,
+            PriceCurve::ExponentialDecay { half_life: 100 },
+            PriceCurve::ExponentialDecay { half_life: 5000 },
+            PriceCurve::Stepwise {
+                step_duration: 60,
+                decrement: dec("7"),
+            },
+            PriceCurve::PiecewiseLinear {
+                breakpoints: vec![(100, dec("50")), (500, dec("45")), (600, dec("20"))],
+            },
+        ]
+    }
+
+    #[test]
+    fn test_curves_are_monotonic_and_bounded() {
+        for curve in curves() {
+            curve.validate(dec("100"), dec("10"), 1000).unwrap();
+
+            assert_eq!(
+                curve.price_at(dec("100"), dec("10"), 0, DURATION),
+                dec("100")
+            );
+            assert_eq!(
+                curve.price_at(dec("100"), dec("10"), DURATION, DURATION),
+                dec("10")
+            );
+
+            let mut previous = dec("100");
+            for second in 0..=1000u64 {
+                let price =
+                    curve.price_at(dec("100"), dec("10"), second * NANOS_PER_SECOND, DURATION);
+                assert!(price <= previous, "{curve:?} increased at {second}");
+                assert!(price >= dec("10"), "{curve:?} below end price at {second}");
+                previous = price;
+            }
+        }
+    }
+
+    #[test]
+    fn test_curve_prices() {
+        let curve = PriceCurve::ExponentialDecay { half_life: 100 };
+        // 0.5^5 rescaled from [0.5^10, 1]
+        let price = curve.price_at(dec("100"), dec("10"), 500 * NANOS_PER_SECOND, DURATION);
+        assert!(price > dec("12.7") && price < dec("12.8"));
+
+        let curve = PriceCurve::Stepwise {
+            step_duration: 60,
+            decrement: dec("7"),
+        };
+        assert_eq!(
+            curve.price_at(dec("100"), dec("10"), 59 * NANOS_PER_SECOND, DURATION),
+            dec("100")
+        );
+        assert_eq!(
+            curve.price_at(dec("100"), dec("10"), 130 * NANOS_PER_SECOND, DURATION),
+            dec("86")
+        );
+        assert_eq!(
+            curve.price_at(dec("100"), dec("10"), 900 * NANOS_PER_SECOND, DURATION),
+            dec("10")
+        );
+
+        let curve = PriceCurve::PiecewiseLinear {
+            breakpoints: vec![(100, dec("50")), (500, dec("45"))],
+        };
+        assert_eq!(
+            curve.price_at(dec("100"), dec("10"), 50 * NANOS_PER_SECOND, DURATION),
+            dec("75")
+        );
+        assert_eq!(
+            curve.price_at(dec("100"), dec("10"), 300 * NANOS_PER_SECOND, DURATION),
+            dec("47.5")
+        );
+        assert_eq!(
+            curve.price_at(dec("100"), dec("10"), 750 * NANOS_PER_SECOND, DURATION),
+            dec("27.5")
+        );
+    }
+
+    #[test]
+    fn test_validate_curves() {
+        let invalid_curves = vec![
+            PriceCurve::ExponentialDecay { half_life: 0 },
+            PriceCurve::Stepwise {
+                step_duration: 0,
+                decrement: dec("1"),
+            },
+            PriceCurve::Stepwise {
+                step_duration: 1001,
+                decrement: dec("1"),
+            },
+            PriceCurve::Stepwise {
+                step_duration: 10,
+                decrement: Decimal::zero(),
+            },
+            PriceCurve::PiecewiseLinear {
+                breakpoints: vec![(500, dec("50")), (100, dec("40"))],
+            },
+            PriceCurve::PiecewiseLinear {
+                breakpoints: vec![(100, dec("50")), (500, dec("60"))],
+            },
+            PriceCurve::PiecewiseLinear {
+                breakpoints: vec![(100, dec("5"))],
+            },
+            PriceCurve::PiecewiseLinear {
+                breakpoints: vec![(1000, dec("50"))],
+            },
+        ];
+        for curve in invalid_curves {
+            assert!(matches!(
+                curve.validate(dec("100"), dec("10"), 1000),
+                Err(ContractError::InvalidPriceCurve { .. })
+            ));
+        }
+    }
+}
@@ -1,13 +1,60 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::Addr;
 use cosmwasm_std::Coin;
+use cosmwasm_std::Decimal;
+use cosmwasm_std::Empty;
+use cosmwasm_std::Uint128;
 use cw_storage_plus::Item;
 use cw_storage_plus::Map;
 
 use crate::auction::Auction;
 
-pub const AUCTIONS: Map<u8, Auction> = Map::new("auctions");
-pub const AUCTION_INDEX: Item<u8> = Item::new("auction_index");
+pub const AUCTIONS: Map<u64, Auction> = Map::new("auctions");
+pub const AUCTION_INDEX: Item<u64> = Item::new("auction_index");
+/// Payments committed by each bidder of a uniform price auction
+pub const COMMITMENTS: Map<(u64, &Addr), Uint128> = Map::new("commitments");
+/// Settlement progress of each uniform price auction
+pub const SETTLEMENTS: Map<u64, Settlement> = Map::new("settlements");
+/// Creation fees paid ahead of creating a CW20 funded auction
+pub const CREATION_FEE_DEPOSITS: Map<&Addr, Coin> = Map::new("creation_fee_deposits");
+
+/// Addresses allowed to bid in allowlist-only auctions
+pub const ALLOWLIST: Map<(u64, &Addr), Empty> = Map::new("allowlist");
+/// Purchase history of each bidder in an auction
+pub const BIDS: Map<(u64, &Addr), BidderPosition> = Map::new("bids");
+/// Proceeds of an auction paid in each of its quote denoms
+pub const QUOTE_PROCEEDS: Map<(u64, &str), QuoteProceeds> = Map::new("quote_proceeds");
+
+#[cw_serde]
+#[derive(Default)]
+pub struct QuoteProceeds {
+    pub total_raised: Uint128,
+    pub total_claimed: Uint128,
+}
+
+/// Commitments of a uniform price auction and how many of them are settled.
+#[cw_serde]
+#[derive(Default)]
+pub struct Settlement {
+    /// Commitments not settled yet
+    pub open_commitments: u64,
+    /// Payments of the settled commitments, refunds included
+    pub settled_committed: Uint128,
+    pub settled_cost: Uint128,
+    /// Tokens bought by the settled commitments
+    pub settled_amount: Uint128,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct BidderPosition {
+    /// Payments kept by the auction in `in_denom` terms, refunds excluded
+    pub total_paid: Uint128,
+    pub total_acquired: Uint128,
+    /// Acquired tokens already delivered to the bidder
+    pub claimed_amount: Uint128,
+    pub bid_count: u64,
+}
 
 #[cw_serde]
 pub struct Params {
@@ -15,6 +62,33 @@ pub struct Params {
     pub admin: Addr,
     pub min_seconds_until_auction_start: u64,
     pub max_auction_duration: u64,
-    pub accepted_denoms: Vec<String>,
+    pub accepted_denoms: Vec<AcceptedDenom>,
+    /// Share of the creation fee that is held by the contract and returned to
+    /// the creator if the auction is cancelled before it starts.
+    pub cancellation_fee_refund: Decimal,
+    /// Longest bought tokens or proceeds may stay locked after an auction
+    /// ends, in seconds
+    pub max_vesting_duration: u64,
+}
+
+impl Params {
+    pub fn accepted_denom(&self, denom: &str) -> Option<&AcceptedDenom> {
+        self.accepted_denoms
+            .iter()
+            .find(|accepted| accepted.denom == denom)
+    }
 }
+
 pub const PARAMS: Item<Params> = Item::new("params");
+
+/// Default `max_vesting_duration`, four years.
+pub const DEFAULT_MAX_VESTING_DURATION: u64 = 4 * 365 * 24 * 60 * 60;
+
+/// A denom auctions may be priced in, with optional limits that apply to
+/// auctions using it.
+#[cw_serde]
+pub struct AcceptedDenom {
+    pub denom: String,
+    pub min_starting_price: Option<Decimal>,
+    pub min_bid_size: Option<Uint128>,
+}
@@ -1 +1,2288 @@
+#![cfg(test)]
 
+use std::str::FromStr;
+
+use cosmwasm_std::{coin, to_json_binary, Addr, Coin, Decimal, HexBinary, Timestamp, Uint128};
+use cw20::{BalanceResponse, Cw20Coin, Cw20ExecuteMsg, Cw20QueryMsg};
+use cw_multi_test::{App, AppResponse, Contract, ContractWrapper, Executor};
+use cw_utils::PaymentError;
+
+use crate::asset::AssetInfo;
+use crate::auction::{
+    Auction, AuctionPhase, AuctionStatus, QuoteDenom, SettlementMode, TokenDelivery,
+};
+use crate::contract::{execute, instantiate, migrate, query, ContractMsg};
+use crate::merkle::{MerkleProof, MerkleTree};
+use crate::migrations::v0_1;
+use crate::msg::{
+    AuctionOptions, ClaimableResponse, CurrentPriceResponse, Cw20HookMsg, DenomProceeds,
+    ExecuteMsg, InstantiateMsg, MigrateMsg, ProceedsResponse, QueryMsg, SimulateBidResponse,
+};
+use crate::price_curve::PriceCurve;
+use crate::state::{AcceptedDenom, BidderPosition, Params};
+use crate::vesting::VestingSchedule;
+use crate::ContractError;
+
+const OFFERED_DENOM: &str = "uoffer";
+const IN_DENOM: &str = "uusd";
+const FEE_DENOM: &str = "ufee";
+
+#[cfg(not(feature = "tokenfactory"))]
+type TestApp = App;
+#[cfg(feature = "tokenfactory")]
+type TestApp = App<
+    cw_multi_test::BankKeeper,
+    cosmwasm_std::testing::MockApi,
+    cosmwasm_std::testing::MockStorage,
+    token_factory_stub::TokenFactoryModule,
+    cw_multi_test::WasmKeeper<ContractMsg, cosmwasm_std::Empty>,
+>;
+
+fn contract_dutch_auction() -> Box<dyn Contract<ContractMsg>> {
+    Box::new(ContractWrapper::new(execute, instantiate, query).with_migrate(migrate))
+}
+
+fn contract_cw20() -> Box<dyn Contract<ContractMsg>> {
+    Box::new(ContractWrapper::new_with_empty(
+        cw20_base::contract::execute,
+        cw20_base::contract::instantiate,
+        cw20_base::contract::query,
+    ))
+}
+
+struct Suite {
+    app: TestApp,
+    code_id: u64,
+    contract: Addr,
+    admin: Addr,
+    creator: Addr,
+    bidder: Addr,
+    other_bidder: Addr,
+}
+
+impl Suite {
+    fn new() -> Self {
+        #[cfg(not(feature = "tokenfactory"))]
+        let mut app = App::default();
+        #[cfg(feature = "tokenfactory")]
+        let mut app = cw_multi_test::AppBuilder::new_custom()
+            .with_custom(token_factory_stub::TokenFactoryModule)
+            .build(|_, _, _| {});
+        let admin = app.api().addr_make("admin");
+        let creator = app.api().addr_make("creator");
+        let bidder = app.api().addr_make("bidder");
+        let other_bidder = app.api().addr_make("other_bidder");
+
+        app.init_modules(|router, _, storage| {
+            router
+                .bank
+                .init_balance(
+                    storage,
+                    &creator,
+                    vec![coin(1_000_000, OFFERED_DENOM), coin(1_000, FEE_DENOM)],
+                )
+                .unwrap();
+            router
+                .bank
+                .init_balance(storage, &bidder, vec![coin(1_000_000, IN_DENOM)])
+                .unwrap();
+            router
+                .bank
+                .init_balance(storage, &other_bidder, vec![coin(1_000_000, IN_DENOM)])
+                .unwrap();
+        });
+
+        let code_id = app.store_code(contract_dutch_auction());
+        let contract = app
+            .instantiate_contract(
+                code_id,
+                admin.clone(),
+                &InstantiateMsg {
+                    auction_creation_fee: coin(100, FEE_DENOM),
+                    admin: admin.to_string(),
+                    min_seconds_until_auction_start: 10,
+                    max_auction_duration: 10_000,
+                    accepted_denoms: vec![AcceptedDenom {
+                        denom: IN_DENOM.to_string(),
+                        min_starting_price: None,
+                        min_bid_size: None,
+                    }],
+                    cancellation_fee_refund: None,
+                    max_vesting_duration: Some(5_000),
+                },
+                &[],
+                "dutch-auction",
+                Some(admin.to_string()),
+            )
+            .unwrap();
+
+        Suite {
+            app,
+            code_id,
+            contract,
+            admin,
+            creator,
+            bidder,
+            other_bidder,
+        }
+    }
+
+    fn now(&self) -> Timestamp {
+        self.app.block_info().time
+    }
+
+    fn set_time(&mut self, time: Timestamp) {
+        self.app.update_block(|block| block.time = time);
+    }
+
+    /// Creates an auction selling 1000 tokens from price 10 down to 1 over
+    /// 1000 seconds, starting 100 seconds from now.
+    fn create_auction(&mut self) -> Timestamp {
+        self.create_auction_with(|_| {}).unwrap()
+    }
+
+    /// Options of the auction made by `create_auction`.
+    fn auction_options(&self) -> AuctionOptions {
+        let start_time = self.now().plus_seconds(100);
+        AuctionOptions {
+            in_denom: IN_DENOM.to_string(),
+            starting_price: Decimal::from_str("10").unwrap(),
+            end_price: Decimal::from_str("1").unwrap(),
+            start_time,
+            end_time: start_time.plus_seconds(1_000),
+            price_curve: None,
+            step_interval: None,
+            settlement_mode: None,
+            max_per_wallet: None,
+            max_bid_size: None,
+            allowlist_only: None,
+            merkle_root: None,
+            min_raise: None,
+            token_delivery: None,
+            proceeds_vesting: None,
+            quote_denoms: None,
+        }
+    }
+
+    /// Same as `create_auction`, with `configure` applied to the options first.
+    fn create_auction_with(
+        &mut self,
+        configure: impl FnOnce(&mut AuctionOptions),
+    ) -> anyhow::Result<Timestamp> {
+        let mut options = self.auction_options();
+        configure(&mut options);
+        let start_time = options.start_time;
+        let msg = ExecuteMsg::CreateAuction {
+            offered_asset: coin(1_000, OFFERED_DENOM),
+            options,
+        };
+        self.app.execute_contract(
+            self.creator.clone(),
+            self.contract.clone(),
+            &msg,
+            &[coin(1_000, OFFERED_DENOM), coin(100, FEE_DENOM)],
+        )?;
+        Ok(start_time)
+    }
+
+    fn bid(&mut self, auction_id: u64, funds: &[Coin]) -> anyhow::Result<AppResponse> {
+        self.bid_with_slippage(auction_id, funds, None, None)
+    }
+
+    fn bid_as(
+        &mut self,
+        sender: &Addr,
+        auction_id: u64,
+        funds: &[Coin],
+    ) -> anyhow::Result<AppResponse> {
+        self.bid_with_proof(sender, auction_id, funds, None)
+    }
+
+    fn bid_with_proof(
+        &mut self,
+        sender: &Addr,
+        auction_id: u64,
+        funds: &[Coin],
+        merkle_proof: Option<MerkleProof>,
+    ) -> anyhow::Result<AppResponse> {
+        self.app.execute_contract(
+            sender.clone(),
+            self.contract.clone(),
+            &ExecuteMsg::Bid {
+                auction_id,
+                max_price: None,
+                min_amount_out: None,
+                merkle_proof,
+            },
+            funds,
+        )
+    }
+
+    fn bid_with_slippage(
+        &mut self,
+        auction_id: u64,
+        funds: &[Coin],
+        max_price: Option<Decimal>,
+        min_amount_out: Option<Uint128>,
+    ) -> anyhow::Result<AppResponse> {
+        self.app.execute_contract(
+            self.bidder.clone(),
+            self.contract.clone(),
+            &ExecuteMsg::Bid {
+                auction_id,
+                max_price,
+                min_amount_out,
+                merkle_proof: None,
+            },
+            funds,
+        )
+    }
+
+    fn cancel_auction(&mut self, auction_id: u64) -> anyhow::Result<AppResponse> {
+        self.app.execute_contract(
+            self.creator.clone(),
+            self.contract.clone(),
+            &ExecuteMsg::CancelAuction { auction_id },
+            &[],
+        )
+    }
+
+    fn claim_proceeds(&mut self, sender: &Addr, auction_id: u64) -> anyhow::Result<AppResponse> {
+        self.app.execute_contract(
+            sender.clone(),
+            self.contract.clone(),
+            &ExecuteMsg::ClaimProceeds { auction_id },
+            &[],
+        )
+    }
+
+    fn finalize_auction(&mut self, sender: &Addr, auction_id: u64) -> anyhow::Result<AppResponse> {
+        self.app.execute_contract(
+            sender.clone(),
+            self.contract.clone(),
+            &ExecuteMsg::FinalizeAuction { auction_id },
+            &[],
+        )
+    }
+
+    fn claim_settlement(&mut self, sender: &Addr, auction_id: u64) -> anyhow::Result<AppResponse> {
+        self.app.execute_contract(
+            sender.clone(),
+            self.contract.clone(),
+            &ExecuteMsg::ClaimSettlement { auction_id },
+            &[],
+        )
+    }
+
+    fn claim_tokens(&mut self, sender: &Addr, auction_id: u64) -> anyhow::Result<AppResponse> {
+        self.app.execute_contract(
+            sender.clone(),
+            self.contract.clone(),
+            &ExecuteMsg::ClaimTokens { auction_id },
+            &[],
+        )
+    }
+
+    fn claim_vested(&mut self, sender: &Addr, auction_id: u64) -> anyhow::Result<AppResponse> {
+        self.app.execute_contract(
+            sender.clone(),
+            self.contract.clone(),
+            &ExecuteMsg::ClaimVested { auction_id },
+            &[],
+        )
+    }
+
+    fn claim_refund(&mut self, sender: &Addr, auction_id: u64) -> anyhow::Result<AppResponse> {
+        self.app.execute_contract(
+            sender.clone(),
+            self.contract.clone(),
+            &ExecuteMsg::ClaimRefund { auction_id },
+            &[],
+        )
+    }
+
+    fn migrate(&mut self, legacy_proceeds_recipient: Option<&Addr>) -> anyhow::Result<AppResponse> {
+        self.app.migrate_contract(
+            self.admin.clone(),
+            self.contract.clone(),
+            &MigrateMsg {
+                legacy_proceeds_recipient: legacy_proceeds_recipient.map(Addr::to_string),
+            },
+            self.code_id,
+        )
+    }
+
+    fn simulate_bid(
+        &self,
+        auction_id: u64,
+        amount: Coin,
+        at_time: Option<Timestamp>,
+    ) -> SimulateBidResponse {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                &self.contract,
+                &QueryMsg::SimulateBid {
+                    auction_id,
+                    amount,
+                    at_time,
+                },
+            )
+            .unwrap()
+    }
+
+    fn current_price(&self, auction_id: u64) -> CurrentPriceResponse {
+        self.app
+            .wrap()
+            .query_wasm_smart(&self.contract, &QueryMsg::CurrentPrice { auction_id })
+            .unwrap()
+    }
+
+    fn bidder_position(&self, auction_id: u64, bidder: &Addr) -> BidderPosition {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                &self.contract,
+                &QueryMsg::BidderPosition {
+                    auction_id,
+                    bidder: bidder.to_string(),
+                },
+            )
+            .unwrap()
+    }
+
+    fn query_auction(&self, auction_id: u64) -> Auction {
+        self.app
+            .wrap()
+            .query_wasm_smart(&self.contract, &QueryMsg::Auction { auction_id })
+            .unwrap()
+    }
+
+    fn claimable(&self, auction_id: u64, bidder: &Addr) -> ClaimableResponse {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                &self.contract,
+                &QueryMsg::Claimable {
+                    auction_id,
+                    bidder: bidder.to_string(),
+                },
+            )
+            .unwrap()
+    }
+
+    fn balance(&self, address: &Addr, denom: &str) -> Uint128 {
+        self.app
+            .wrap()
+            .query_balance(address, denom)
+            .unwrap()
+            .amount
+    }
+
+    /// Instantiates a CW20 token with `amount` minted to `holder`.
+    fn instantiate_cw20(&mut self, holder: &Addr, amount: u128) -> Addr {
+        let code_id = self.app.store_code(contract_cw20());
+        self.app
+            .instantiate_contract(
+                code_id,
+                self.admin.clone(),
+                &cw20_base::msg::InstantiateMsg {
+                    name: "Launch Token".to_string(),
+                    symbol: "LAUNCH".to_string(),
+                    decimals: 6,
+                    initial_balances: vec![Cw20Coin {
+                        address: holder.to_string(),
+                        amount: Uint128::new(amount),
+                    }],
+                    mint: None,
+                    marketing: None,
+                },
+                &[],
+                "cw20",
+                None,
+            )
+            .unwrap()
+    }
+
+    fn cw20_balance(&self, token: &Addr, address: &Addr) -> Uint128 {
+        let res: BalanceResponse = self
+            .app
+            .wrap()
+            .query_wasm_smart(
+                token,
+                &Cw20QueryMsg::Balance {
+                    address: address.to_string(),
+                },
+            )
+            .unwrap();
+        res.balance
+    }
+
+    /// Creates the default auction, offering `amount` of a CW20 token.
+    fn create_cw20_auction(&mut self, token: &Addr, amount: u128) -> anyhow::Result<Timestamp> {
+        let options = self.auction_options();
+        let start_time = options.start_time;
+        let hook = Cw20HookMsg::CreateAuction { options };
+        self.app.execute_contract(
+            self.creator.clone(),
+            token.clone(),
+            &Cw20ExecuteMsg::Send {
+                contract: self.contract.to_string(),
+                amount: Uint128::new(amount),
+                msg: to_json_binary(&hook)?,
+            },
+            &[],
+        )?;
+        Ok(start_time)
+    }
+}
+
+#[test]
+fn test_claim_proceeds() {
+    let mut suite = Suite::new();
+    let start_time = suite.create_auction();
+    suite.set_time(start_time);
+
+    // Price is 10 at start time, 100 uusd buys 10 tokens
+    suite.bid(1, &[coin(100, IN_DENOM)]).unwrap();
+    let auction = suite.query_auction(1);
+    assert_eq!(auction.total_raised, Uint128::new(100));
+    assert_eq!(auction.total_claimed, Uint128::zero());
+
+    // Only the creator can claim
+    let bidder = suite.bidder.clone();
+    let err = suite.claim_proceeds(&bidder, 1).unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::Unauthorized {}
+    ));
+
+    // Creator claims mid-auction
+    let creator = suite.creator.clone();
+    suite.claim_proceeds(&creator, 1).unwrap();
+    assert_eq!(suite.balance(&creator, IN_DENOM), Uint128::new(100));
+
+    // Nothing left to claim
+    let err = suite.claim_proceeds(&creator, 1).unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::NoProceedsToClaim {}
+    ));
+
+    // Bid again after the auction has ended, then claim the rest
+    suite.set_time(start_time.plus_seconds(500));
+    suite.bid(1, &[coin(55, IN_DENOM)]).unwrap();
+    suite.set_time(start_time.plus_seconds(2_000));
+    suite.claim_proceeds(&creator, 1).unwrap();
+    assert_eq!(suite.balance(&creator, IN_DENOM), Uint128::new(155));
+
+    let auction = suite.query_auction(1);
+    assert_eq!(auction.total_raised, Uint128::new(155));
+    assert_eq!(auction.total_claimed, Uint128::new(155));
+    assert_eq!(suite.balance(&suite.admin, FEE_DENOM), Uint128::new(100));
+}
+
+#[test]
+fn test_finalize_auction_returns_unsold_tokens() {
+    let mut suite = Suite::new();
+    let start_time = suite.create_auction();
+    suite.set_time(start_time);
+    suite.bid(1, &[coin(100, IN_DENOM)]).unwrap();
+
+    // Can not finalize while the auction is running
+    let bidder = suite.bidder.clone();
+    let err = suite.finalize_auction(&bidder, 1).unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::AuctionNotEnded {}
+    ));
+
+    // Anyone can finalize once the auction is expired
+    suite.set_time(start_time.plus_seconds(1_001));
+    suite.finalize_auction(&bidder, 1).unwrap();
+
+    let creator = suite.creator.clone();
+    assert_eq!(
+        suite.balance(&creator, OFFERED_DENOM),
+        Uint128::new(1_000_000 - 10)
+    );
+    let auction = suite.query_auction(1);
+    assert_eq!(auction.status, AuctionStatus::Finalized);
+    assert_eq!(auction.remaining_amount, Uint128::zero());
+
+    // Can not settle twice
+    let err = suite.finalize_auction(&creator, 1).unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::AuctionAlreadyFinalized {}
+    ));
+}
+
+#[test]
+fn test_cancel_auction_refunds_escrow() {
+    let mut suite = Suite::new();
+    suite
+        .app
+        .execute_contract(
+            suite.admin.clone(),
+            suite.contract.clone(),
+            &ExecuteMsg::ChangeParams {
+                auction_creation_fee: None,
+                min_seconds_until_auction_start: None,
+                max_auction_duration: None,
+                accepted_denoms: None,
+                cancellation_fee_refund: Some(Decimal::percent(50)),
+                max_vesting_duration: None,
+                admin: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+    let start_time = suite.create_auction();
+    let admin = suite.admin.clone();
+    let creator = suite.creator.clone();
+    assert_eq!(suite.balance(&admin, FEE_DENOM), Uint128::new(50));
+
+    suite.cancel_auction(1).unwrap();
+    assert_eq!(
+        suite.balance(&creator, OFFERED_DENOM),
+        Uint128::new(1_000_000)
+    );
+    assert_eq!(suite.balance(&creator, FEE_DENOM), Uint128::new(950));
+
+    // The cancelled auction is kept as a tombstone
+    let auction = suite.query_auction(1);
+    assert_eq!(auction.status, AuctionStatus::Cancelled);
+    assert_eq!(auction.remaining_amount, Uint128::zero());
+    assert_eq!(auction.held_creation_fee, None);
+
+    let err = suite.cancel_auction(1).unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::AuctionCannotBeCanceled {}
+    ));
+
+    suite.set_time(start_time);
+    let err = suite.bid(1, &[coin(100, IN_DENOM)]).unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::AuctionNotActive {}
+    ));
+
+    // Once an auction can no longer be cancelled the held fee goes to the admin
+    let start_time = suite.create_auction();
+    suite.set_time(start_time.plus_seconds(1_001));
+    suite.finalize_auction(&creator, 2).unwrap();
+    assert_eq!(suite.balance(&admin, FEE_DENOM), Uint128::new(150));
+}
+
+#[test]
+fn test_migrate_from_v0_1() {
+    let mut suite = Suite::new();
+    let contract = suite.contract.clone();
+    let admin = suite.admin.clone();
+    let creator = suite.creator.clone();
+
+    // Rewrite the contract state into the v0.1 layout
+    {
+        let mut storage = suite.app.contract_storage_mut(&contract);
+        cw2::set_contract_version(
+            storage.as_mut(),
+            "crates.io:dutch-auction-launchpad",
+            "0.1.0",
+        )
+        .unwrap();
+        v0_1::PARAMS
+            .save(
+                storage.as_mut(),
+                &v0_1::Params {
+                    auction_creation_fee: coin(100, FEE_DENOM),
+                    admin: admin.clone(),
+                    min_seconds_until_auction_start: 10,
+                    max_auction_duration: 10_000,
+                    accepted_denoms: vec![IN_DENOM.to_string()],
+                },
+            )
+            .unwrap();
+        for auction_id in 1..=3u8 {
+            v0_1::AUCTIONS
+                .save(
+                    storage.as_mut(),
+                    auction_id,
+                    &v0_1::Auction {
+                        creator: creator.to_string(),
+                        offered_asset: coin(1_000, OFFERED_DENOM),
+                        in_denom: IN_DENOM.to_string(),
+                        starting_price: Decimal::from_str("10").unwrap(),
+                        end_price: Decimal::from_str("1").unwrap(),
+                        start_time: Timestamp::from_seconds(1_000),
+                        end_time: Timestamp::from_seconds(2_000),
+                        remaining_amount: Uint128::new(600),
+                    },
+                )
+                .unwrap();
+        }
+        v0_1::AUCTION_INDEX.save(storage.as_mut(), &3).unwrap();
+    }
+    // The v0.1 contract held the unsold tokens and every bid payment
+    suite.app.init_modules(|router, _, storage| {
+        router
+            .bank
+            .init_balance(
+                storage,
+                &contract,
+                vec![coin(1_800, OFFERED_DENOM), coin(4_000, IN_DENOM)],
+            )
+            .unwrap();
+    });
+
+    // Payments were never booked per auction, someone has to take them over
+    let err = suite.migrate(None).unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::LegacyProceedsRecipientRequired { proceeds } if proceeds == "4000uusd"
+    ));
+    let recipient = suite.app.api().addr_make("legacy_payout");
+    suite.migrate(Some(&recipient)).unwrap();
+    assert_eq!(suite.balance(&recipient, IN_DENOM), Uint128::new(4_000));
+    assert_eq!(suite.balance(&admin, IN_DENOM), Uint128::zero());
+    assert_eq!(suite.balance(&contract, IN_DENOM), Uint128::zero());
+    assert_eq!(suite.balance(&contract, OFFERED_DENOM), Uint128::new(1_800));
+
+    let version = cw2::query_contract_info(&suite.app.wrap(), &contract).unwrap();
+    assert_eq!(version.version, env!("CARGO_PKG_VERSION"));
+
+    let params: Params = suite
+        .app
+        .wrap()
+        .query_wasm_smart(&contract, &QueryMsg::Params {})
+        .unwrap();
+    assert_eq!(params.admin, admin);
+    assert_eq!(params.cancellation_fee_refund, Decimal::zero());
+
+    let auctions: Vec<(u64, Auction)> = suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &contract,
+            &QueryMsg::Auctions {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(auctions.len(), 3);
+    assert_eq!(auctions[2].0, 3);
+    assert_eq!(auctions[2].1.remaining_amount, Uint128::new(600));
+    assert_eq!(auctions[2].1.status, AuctionStatus::Open);
+
+    // New auctions continue from the migrated index
+    suite.create_auction();
+    assert_eq!(suite.query_auction(4).creator, creator.to_string());
+
+    // Migrating again from the current version is a no-op
+    suite.migrate(None).unwrap();
+    assert_eq!(suite.query_auction(3).remaining_amount, Uint128::new(600));
+}
+
+#[test]
+fn test_migrate_rejects_downgrade_and_foreign_contract() {
+    let mut suite = Suite::new();
+    let contract = suite.contract.clone();
+
+    cw2::set_contract_version(
+        suite.app.contract_storage_mut(&contract).as_mut(),
+        "crates.io:dutch-auction-launchpad",
+        "99.0.0",
+    )
+    .unwrap();
+    let err = suite.migrate(None).unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::CannotMigrateToOlderVersion { .. }
+    ));
+
+    cw2::set_contract_version(
+        suite.app.contract_storage_mut(&contract).as_mut(),
+        "crates.io:some-other-contract",
+        "0.1.0",
+    )
+    .unwrap();
+    let err = suite.migrate(None).unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidContractName { contract } if contract == "crates.io:some-other-contract"
+    ));
+}
+
+#[test]
+fn test_accepted_denoms_are_enforced() {
+    let mut suite = Suite::new();
+    suite
+        .app
+        .execute_contract(
+            suite.admin.clone(),
+            suite.contract.clone(),
+            &ExecuteMsg::ChangeParams {
+                auction_creation_fee: None,
+                min_seconds_until_auction_start: None,
+                max_auction_duration: None,
+                accepted_denoms: Some(vec![AcceptedDenom {
+                    denom: IN_DENOM.to_string(),
+                    min_starting_price: None,
+                    min_bid_size: Some(Uint128::new(50)),
+                }]),
+                cancellation_fee_refund: None,
+                max_vesting_duration: None,
+                admin: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+    let err = suite
+        .create_auction_with(|options| options.in_denom = "uatom".to_string())
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InDenomNotAccepted { denom } if denom == "uatom"
+    ));
+
+    let start_time = suite.create_auction();
+    suite.set_time(start_time);
+    let err = suite.bid(1, &[coin(49, IN_DENOM)]).unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::BidTooSmall { min_bid_size, amount }
+            if min_bid_size == Uint128::new(50) && amount == Uint128::new(49)
+    ));
+    suite.bid(1, &[coin(50, IN_DENOM)]).unwrap();
+}
+
+#[test]
+fn test_bid_buys_out_remaining_supply() {
+    let mut suite = Suite::new();
+    let start_time = suite.create_auction();
+    suite.set_time(start_time);
+    let bidder = suite.bidder.clone();
+
+    suite.bid(1, &[coin(1_000, IN_DENOM)]).unwrap();
+
+    // 900 tokens are left at a price of 10, the last bidder overpays by 1000
+    let res = suite.bid(1, &[coin(10_000, IN_DENOM)]).unwrap();
+    let wasm = res.events.iter().find(|ev| ev.ty == "wasm").unwrap();
+    assert!(wasm
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "refunded_amount" && attr.value == "1000"));
+    assert_eq!(suite.balance(&bidder, OFFERED_DENOM), Uint128::new(1_000));
+    assert_eq!(
+        suite.balance(&bidder, IN_DENOM),
+        Uint128::new(1_000_000 - 10_000)
+    );
+
+    let auction = suite.query_auction(1);
+    assert_eq!(auction.status, AuctionStatus::SoldOut);
+    assert_eq!(auction.remaining_amount, Uint128::zero());
+    assert_eq!(auction.total_raised, Uint128::new(10_000));
+
+    let err = suite.bid(1, &[coin(100, IN_DENOM)]).unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::AuctionSoldOut {}
+    ));
+
+    // Sold out auctions can be finalized before end time
+    suite.finalize_auction(&bidder, 1).unwrap();
+    assert_eq!(suite.query_auction(1).status, AuctionStatus::Finalized);
+}
+
+#[test]
+fn test_bid_slippage_protection() {
+    let mut suite = Suite::new();
+    let start_time = suite.create_auction();
+    // Price is 5.5 half way through the auction
+    suite.set_time(start_time.plus_seconds(500));
+
+    let err = suite
+        .bid_with_slippage(
+            1,
+            &[coin(110, IN_DENOM)],
+            Some(Decimal::from_str("5").unwrap()),
+            None,
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::PriceAboveMaxPrice { price, .. } if price == Decimal::from_str("5.5").unwrap()
+    ));
+
+    let err = suite
+        .bid_with_slippage(1, &[coin(110, IN_DENOM)], None, Some(Uint128::new(21)))
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::AmountBelowMinAmountOut { acquired_amount, .. } if acquired_amount == Uint128::new(20)
+    ));
+
+    suite
+        .bid_with_slippage(
+            1,
+            &[coin(110, IN_DENOM)],
+            Some(Decimal::from_str("5.5").unwrap()),
+            Some(Uint128::new(20)),
+        )
+        .unwrap();
+    assert_eq!(
+        suite.balance(&suite.bidder, OFFERED_DENOM),
+        Uint128::new(20)
+    );
+}
+
+#[test]
+fn test_simulate_bid() {
+    let mut suite = Suite::new();
+    let start_time = suite.create_auction();
+
+    // Not started yet
+    let res = suite.simulate_bid(1, coin(100, IN_DENOM), None);
+    assert_eq!(
+        res.error,
+        Some(ContractError::AuctionNotActive {}.to_string())
+    );
+    assert_eq!(res.price, Decimal::from_str("10").unwrap());
+    assert_eq!(res.refund_amount, Uint128::new(100));
+
+    // Simulating at a future time matches the bid placed at that time
+    let at_time = start_time.plus_seconds(300);
+    let res = suite.simulate_bid(1, coin(100, IN_DENOM), Some(at_time));
+    assert_eq!(
+        res,
+        SimulateBidResponse {
+            price: Decimal::from_str("7.3").unwrap(),
+            acquired_amount: Uint128::new(13),
+            cost: Uint128::new(95),
+            refund_amount: Uint128::new(5),
+            error: None,
+        }
+    );
+    suite.set_time(at_time);
+    suite.bid(1, &[coin(100, IN_DENOM)]).unwrap();
+    assert_eq!(
+        suite.balance(&suite.bidder, IN_DENOM),
+        Uint128::new(1_000_000 - 95)
+    );
+
+    let res = suite.simulate_bid(1, coin(100, "uatom"), None);
+    assert!(res.error.is_some());
+    assert_eq!(res.acquired_amount, Uint128::zero());
+
+    // Buying out the rest
+    suite.bid(1, &[coin(100_000, IN_DENOM)]).unwrap();
+    let res = suite.simulate_bid(1, coin(100, IN_DENOM), None);
+    assert_eq!(
+        res.error,
+        Some(ContractError::AuctionSoldOut {}.to_string())
+    );
+}
+
+#[test]
+fn test_current_price() {
+    let mut suite = Suite::new();
+    let start_time = suite.create_auction();
+
+    assert_eq!(
+        suite.current_price(1),
+        CurrentPriceResponse {
+            price: Decimal::from_str("10").unwrap(),
+            phase: AuctionPhase::Pending,
+            seconds_until_start: 100,
+            seconds_until_end: 1_100,
+            remaining_amount: Uint128::new(1_000),
+            next_price_change: None,
+        }
+    );
+
+    suite.set_time(start_time.plus_seconds(250));
+    suite.bid(1, &[coin(775, IN_DENOM)]).unwrap();
+    assert_eq!(
+        suite.current_price(1),
+        CurrentPriceResponse {
+            price: Decimal::from_str("7.75").unwrap(),
+            phase: AuctionPhase::Active,
+            seconds_until_start: 0,
+            seconds_until_end: 750,
+            remaining_amount: Uint128::new(900),
+            next_price_change: None,
+        }
+    );
+
+    suite.set_time(start_time.plus_seconds(1_500));
+    let res = suite.current_price(1);
+    assert_eq!(res.price, Decimal::from_str("1").unwrap());
+    assert_eq!(res.phase, AuctionPhase::Ended);
+    assert_eq!(res.seconds_until_end, 0);
+
+    let start_time = suite.create_auction();
+    suite.set_time(start_time);
+    suite.bid(2, &[coin(10_000, IN_DENOM)]).unwrap();
+    assert_eq!(suite.current_price(2).phase, AuctionPhase::SoldOut);
+}
+
+#[test]
+fn test_create_auction_with_price_curve() {
+    let mut suite = Suite::new();
+
+    let err = suite
+        .create_auction_with(|options| {
+            options.price_curve = Some(PriceCurve::ExponentialDecay { half_life: 0 });
+        })
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidPriceCurve { .. }
+    ));
+
+    let start_time = suite
+        .create_auction_with(|options| {
+            options.price_curve = Some(PriceCurve::Stepwise {
+                step_duration: 100,
+                decrement: Decimal::from_str("2").unwrap(),
+            });
+        })
+        .unwrap();
+
+    // Two steps have passed, the price is 10 - 2 * 2
+    suite.set_time(start_time.plus_seconds(250));
+    assert_eq!(
+        suite.current_price(1).price,
+        Decimal::from_str("6").unwrap()
+    );
+    suite.bid(1, &[coin(60, IN_DENOM)]).unwrap();
+    assert_eq!(
+        suite.balance(&suite.bidder, OFFERED_DENOM),
+        Uint128::new(10)
+    );
+
+    suite.set_time(start_time.plus_seconds(999));
+    assert_eq!(
+        suite.current_price(1).price,
+        Decimal::from_str("1").unwrap()
+    );
+}
+
+#[test]
+fn test_stepped_auction() {
+    let mut suite = Suite::new();
+
+    let err = suite
+        .create_auction_with(|options| {
+            options.step_interval = Some(300);
+        })
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidStepInterval {
+            step_interval: 300,
+            duration: 1_000
+        }
+    ));
+
+    let start_time = suite
+        .create_auction_with(|options| {
+            options.step_interval = Some(200);
+        })
+        .unwrap();
+
+    suite.set_time(start_time.plus_seconds(450));
+    let res = suite.current_price(1);
+    assert_eq!(res.price, Decimal::from_str("6.4").unwrap());
+    assert_eq!(res.next_price_change, Some(start_time.plus_seconds(600)));
+
+    // Bids anywhere in the tick pay the same price
+    suite.bid(1, &[coin(64, IN_DENOM)]).unwrap();
+    suite.set_time(start_time.plus_seconds(599));
+    suite.bid(1, &[coin(64, IN_DENOM)]).unwrap();
+    assert_eq!(
+        suite.balance(&suite.bidder, OFFERED_DENOM),
+        Uint128::new(20)
+    );
+}
+
+#[test]
+fn test_uniform_price_settlement() {
+    let mut suite = Suite::new();
+    let start_time = suite
+        .create_auction_with(|options| {
+            options.settlement_mode = Some(SettlementMode::UniformPrice);
+        })
+        .unwrap();
+    let bidder = suite.bidder.clone();
+    let other_bidder = suite.other_bidder.clone();
+    let creator = suite.creator.clone();
+
+    // Commit 2200 at a price of 5.5, no tokens are delivered yet
+    suite.set_time(start_time.plus_seconds(500));
+    suite.bid_as(&bidder, 1, &[coin(2_200, IN_DENOM)]).unwrap();
+    assert_eq!(suite.balance(&bidder, OFFERED_DENOM), Uint128::zero());
+    assert_eq!(suite.query_auction(1).total_committed, Uint128::new(2_200));
+
+    let err = suite.claim_settlement(&bidder, 1).unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::AuctionNotSettled {}
+    ));
+
+    // At a price of 2.8 only 600 more fits, the rest is refunded and the auction sells out
+    suite.set_time(start_time.plus_seconds(800));
+    suite
+        .bid_as(&other_bidder, 1, &[coin(2_000, IN_DENOM)])
+        .unwrap();
+    assert_eq!(
+        suite.balance(&other_bidder, IN_DENOM),
+        Uint128::new(1_000_000 - 600)
+    );
+    assert_eq!(suite.current_price(1).phase, AuctionPhase::SoldOut);
+
+    suite.finalize_auction(&creator, 1).unwrap();
+    let auction = suite.query_auction(1);
+    assert_eq!(
+        auction.clearing_price,
+        Some(Decimal::from_str("2.8").unwrap())
+    );
+    assert_eq!(auction.remaining_amount, Uint128::zero());
+    // The creator is paid without waiting for the bidders to settle, less 3
+    // reserved for the rounding refund of each commitment
+    assert_eq!(auction.total_raised, Uint128::new(2_794));
+    assert_eq!(
+        suite.balance(&creator, OFFERED_DENOM),
+        Uint128::new(1_000_000 - 1_000)
+    );
+    suite.claim_proceeds(&creator, 1).unwrap();
+    assert_eq!(suite.balance(&creator, IN_DENOM), Uint128::new(2_794));
+
+    // Both bidders pay the clearing price, each settlement releases its reserve
+    suite.claim_settlement(&bidder, 1).unwrap();
+    assert_eq!(suite.balance(&bidder, OFFERED_DENOM), Uint128::new(785));
+    assert_eq!(
+        suite.balance(&bidder, IN_DENOM),
+        Uint128::new(1_000_000 - 2_198)
+    );
+    assert_eq!(suite.query_auction(1).total_raised, Uint128::new(2_795));
+    suite.claim_settlement(&other_bidder, 1).unwrap();
+    assert_eq!(
+        suite.balance(&other_bidder, OFFERED_DENOM),
+        Uint128::new(214)
+    );
+    // The last settlement returns the token the per bidder rounding left unsold
+    assert_eq!(suite.query_auction(1).total_raised, Uint128::new(2_798));
+    assert_eq!(
+        suite.balance(&creator, OFFERED_DENOM),
+        Uint128::new(1_000_000 - 1_000 + 1)
+    );
+    suite.claim_proceeds(&creator, 1).unwrap();
+    assert_eq!(suite.balance(&creator, IN_DENOM), Uint128::new(2_798));
+    assert_eq!(
+        suite.bidder_position(1, &bidder),
+        BidderPosition {
+            total_paid: Uint128::new(2_198),
+            total_acquired: Uint128::new(785),
+            claimed_amount: Uint128::new(785),
+            bid_count: 1,
+        }
+    );
+
+    let err = suite.claim_settlement(&bidder, 1).unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::NoCommitment {}
+    ));
+    assert_eq!(
+        suite.balance(&suite.contract, OFFERED_DENOM),
+        Uint128::zero()
+    );
+    assert_eq!(suite.balance(&suite.contract, IN_DENOM), Uint128::zero());
+}
+
+#[test]
+fn test_uniform_price_settlement_without_sell_out() {
+    let mut suite = Suite::new();
+    let start_time = suite
+        .create_auction_with(|options| {
+            options.settlement_mode = Some(SettlementMode::UniformPrice);
+        })
+        .unwrap();
+    let bidder = suite.bidder.clone();
+    let creator = suite.creator.clone();
+
+    suite.set_time(start_time);
+    suite.bid_as(&bidder, 1, &[coin(500, IN_DENOM)]).unwrap();
+
+    // Unsold supply goes back to the creator and the bidder buys at the end price
+    suite.set_time(start_time.plus_seconds(1_001));
+    suite.finalize_auction(&creator, 1).unwrap();
+    assert_eq!(
+        suite.query_auction(1).clearing_price,
+        Some(Decimal::from_str("1").unwrap())
+    );
+    assert_eq!(
+        suite.balance(&creator, OFFERED_DENOM),
+        Uint128::new(1_000_000 - 500)
+    );
+    suite.claim_settlement(&bidder, 1).unwrap();
+    assert_eq!(suite.balance(&bidder, OFFERED_DENOM), Uint128::new(500));
+}
+
+#[test]
+fn test_uniform_price_without_commitments() {
+    let mut suite = Suite::new();
+    let start_time = suite
+        .create_auction_with(|options| {
+            options.settlement_mode = Some(SettlementMode::UniformPrice);
+            options.end_price = Decimal::zero();
+        })
+        .unwrap();
+    let creator = suite.creator.clone();
+
+    // A zero end price and no commitments leave a zero clearing price
+    suite.set_time(start_time.plus_seconds(1_001));
+    suite.finalize_auction(&creator, 1).unwrap();
+    let auction = suite.query_auction(1);
+    assert_eq!(auction.status, AuctionStatus::Finalized);
+    assert_eq!(auction.clearing_price, Some(Decimal::zero()));
+    assert_eq!(
+        suite.balance(&creator, OFFERED_DENOM),
+        Uint128::new(1_000_000)
+    );
+}
+
+#[test]
+fn test_bidder_positions() {
+    let mut suite = Suite::new();
+    let start_time = suite.create_auction();
+    let bidder = suite.bidder.clone();
+    let other_bidder = suite.other_bidder.clone();
+
+    suite.set_time(start_time);
+    suite.bid_as(&bidder, 1, &[coin(105, IN_DENOM)]).unwrap();
+    suite
+        .bid_as(&other_bidder, 1, &[coin(50, IN_DENOM)])
+        .unwrap();
+    suite.set_time(start_time.plus_seconds(500));
+    suite.bid_as(&bidder, 1, &[coin(55, IN_DENOM)]).unwrap();
+
+    assert_eq!(
+        suite.bidder_position(1, &bidder),
+        BidderPosition {
+            total_paid: Uint128::new(155),
+            total_acquired: Uint128::new(20),
+            claimed_amount: Uint128::new(20),
+            bid_count: 2,
+        }
+    );
+    assert_eq!(suite.bidder_position(2, &bidder), BidderPosition::default());
+
+    let bids: Vec<(Addr, BidderPosition)> = suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &suite.contract,
+            &QueryMsg::Bids {
+                auction_id: 1,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(bids.len(), 2);
+    assert!(
+        bids.iter()
+            .any(|(addr, position)| addr == other_bidder
+                && position.total_acquired == Uint128::new(5))
+    );
+
+    let page: Vec<(Addr, BidderPosition)> = suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &suite.contract,
+            &QueryMsg::Bids {
+                auction_id: 1,
+                start_after: Some(bids[0].0.to_string()),
+                limit: Some(1),
+            },
+        )
+        .unwrap();
+    assert_eq!(page, vec![bids[1].clone()]);
+}
+
+#[test]
+fn test_purchase_caps() {
+    let mut suite = Suite::new();
+
+    let err = suite
+        .create_auction_with(|options| {
+            options.settlement_mode = Some(SettlementMode::UniformPrice);
+            options.max_per_wallet = Some(Uint128::new(100));
+        })
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::WalletCapRequiresPayAsBid {}
+    ));
+
+    let start_time = suite
+        .create_auction_with(|options| {
+            options.max_per_wallet = Some(Uint128::new(100));
+            options.max_bid_size = Some(Uint128::new(600));
+        })
+        .unwrap();
+    suite.set_time(start_time);
+
+    let err = suite.bid(1, &[coin(601, IN_DENOM)]).unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::BidTooLarge { max_bid_size, amount }
+            if max_bid_size == Uint128::new(600) && amount == Uint128::new(601)
+    ));
+
+    suite.bid(1, &[coin(600, IN_DENOM)]).unwrap();
+    let err = suite.bid(1, &[coin(410, IN_DENOM)]).unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::WalletCapExceeded { total_acquired, .. } if total_acquired == Uint128::new(101)
+    ));
+    suite.bid(1, &[coin(400, IN_DENOM)]).unwrap();
+    assert_eq!(
+        suite.balance(&suite.bidder, OFFERED_DENOM),
+        Uint128::new(100)
+    );
+
+    // The cap is per wallet
+    let other_bidder = suite.other_bidder.clone();
+    suite
+        .bid_as(&other_bidder, 1, &[coin(500, IN_DENOM)])
+        .unwrap();
+}
+
+#[test]
+fn test_allowlist_only_auction() {
+    let mut suite = Suite::new();
+    let start_time = suite
+        .create_auction_with(|options| {
+            options.allowlist_only = Some(true);
+        })
+        .unwrap();
+    let creator = suite.creator.clone();
+    let bidder = suite.bidder.clone();
+    let other_bidder = suite.other_bidder.clone();
+    let add_bidders = ExecuteMsg::AddToAllowlist {
+        auction_id: 1,
+        addresses: vec![bidder.to_string(), other_bidder.to_string()],
+    };
+
+    // Only the creator manages the allowlist
+    let err = suite
+        .app
+        .execute_contract(bidder.clone(), suite.contract.clone(), &add_bidders, &[])
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::Unauthorized {}
+    ));
+    suite
+        .app
+        .execute_contract(creator.clone(), suite.contract.clone(), &add_bidders, &[])
+        .unwrap();
+    suite
+        .app
+        .execute_contract(
+            creator.clone(),
+            suite.contract.clone(),
+            &ExecuteMsg::RemoveFromAllowlist {
+                auction_id: 1,
+                addresses: vec![other_bidder.to_string()],
+            },
+            &[],
+        )
+        .unwrap();
+
+    let allowlist: Vec<Addr> = suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &suite.contract,
+            &QueryMsg::Allowlist {
+                auction_id: 1,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(allowlist, vec![bidder.clone()]);
+
+    suite.set_time(start_time);
+    suite.bid_as(&bidder, 1, &[coin(100, IN_DENOM)]).unwrap();
+    let err = suite
+        .bid_as(&other_bidder, 1, &[coin(100, IN_DENOM)])
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::NotAllowlisted {}
+    ));
+
+    // Public auctions have no allowlist to manage
+    suite.create_auction();
+    let err = suite
+        .app
+        .execute_contract(
+            creator,
+            suite.contract.clone(),
+            &ExecuteMsg::AddToAllowlist {
+                auction_id: 2,
+                addresses: vec![bidder.to_string()],
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::AllowlistNotEnabled {}
+    ));
+}
+
+#[test]
+fn test_merkle_allowlist() {
+    let mut suite = Suite::new();
+    let bidder = suite.bidder.clone();
+    let other_bidder = suite.other_bidder.clone();
+    let entries = vec![
+        (bidder.to_string(), Uint128::new(50)),
+        (other_bidder.to_string(), Uint128::new(10)),
+        ("outsider".to_string(), Uint128::new(1_000)),
+    ];
+    let tree = MerkleTree::new(&entries);
+    let proof_for = |index: usize| MerkleProof {
+        allocation: entries[index].1,
+        proof: tree.proof(index),
+    };
+
+    let err = suite
+        .create_auction_with(|options| {
+            options.merkle_root = Some(HexBinary::from(&[1u8; 31][..]));
+        })
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidMerkleRoot {}
+    ));
+
+    let start_time = suite
+        .create_auction_with(|options| {
+            options.merkle_root = Some(tree.root());
+        })
+        .unwrap();
+    suite.set_time(start_time);
+
+    let err = suite
+        .bid_with_proof(&bidder, 1, &[coin(100, IN_DENOM)], None)
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::MissingMerkleProof {}
+    ));
+
+    // Claiming someone else's allocation does not verify
+    let err = suite
+        .bid_with_proof(&other_bidder, 1, &[coin(100, IN_DENOM)], Some(proof_for(2)))
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidMerkleProof {}
+    ));
+
+    // 300 at price 10 buys 30 of the 50 allocated tokens
+    suite
+        .bid_with_proof(&bidder, 1, &[coin(300, IN_DENOM)], Some(proof_for(0)))
+        .unwrap();
+    let err = suite
+        .bid_with_proof(&bidder, 1, &[coin(300, IN_DENOM)], Some(proof_for(0)))
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::AllocationExceeded { allocation, total_acquired }
+            if allocation == Uint128::new(50) && total_acquired == Uint128::new(60)
+    ));
+    suite
+        .bid_with_proof(&bidder, 1, &[coin(200, IN_DENOM)], Some(proof_for(0)))
+        .unwrap();
+    assert_eq!(
+        suite.bidder_position(1, &bidder).total_acquired,
+        Uint128::new(50)
+    );
+    suite
+        .bid_with_proof(&other_bidder, 1, &[coin(100, IN_DENOM)], Some(proof_for(1)))
+        .unwrap();
+}
+
+#[test]
+fn test_min_raise_reached() {
+    let mut suite = Suite::new();
+    let start_time = suite
+        .create_auction_with(|options| {
+            options.min_raise = Some(Uint128::new(1_500));
+        })
+        .unwrap();
+    let creator = suite.creator.clone();
+    let bidder = suite.bidder.clone();
+    let other_bidder = suite.other_bidder.clone();
+
+    // Bought tokens and proceeds stay in escrow
+    suite.set_time(start_time);
+    suite.bid_as(&bidder, 1, &[coin(1_000, IN_DENOM)]).unwrap();
+    suite
+        .bid_as(&other_bidder, 1, &[coin(1_000, IN_DENOM)])
+        .unwrap();
+    assert_eq!(suite.balance(&bidder, OFFERED_DENOM), Uint128::zero());
+    let err = suite.claim_proceeds(&creator, 1).unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::NoProceedsToClaim {}
+    ));
+    let err = suite.claim_tokens(&bidder, 1).unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::AuctionNotEnded {}
+    ));
+
+    suite.set_time(start_time.plus_seconds(1_001));
+    suite.finalize_auction(&bidder, 1).unwrap();
+    assert_eq!(suite.query_auction(1).status, AuctionStatus::Finalized);
+    assert_eq!(
+        suite.balance(&creator, OFFERED_DENOM),
+        Uint128::new(1_000_000 - 200)
+    );
+
+    suite.claim_tokens(&bidder, 1).unwrap();
+    assert_eq!(suite.balance(&bidder, OFFERED_DENOM), Uint128::new(100));
+    let err = suite.claim_tokens(&bidder, 1).unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::NoTokensToClaim {}
+    ));
+    let err = suite.claim_refund(&other_bidder, 1).unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::AuctionNotFailed {}
+    ));
+    suite.claim_proceeds(&creator, 1).unwrap();
+    assert_eq!(suite.balance(&creator, IN_DENOM), Uint128::new(2_000));
+}
+
+#[test]
+fn test_min_raise_missed_refunds_bidders() {
+    let mut suite = Suite::new();
+    let pay_as_bid_start = suite
+        .create_auction_with(|options| {
+            options.min_raise = Some(Uint128::new(5_000));
+        })
+        .unwrap();
+    suite
+        .create_auction_with(|options| {
+            options.min_raise = Some(Uint128::new(5_000));
+            options.settlement_mode = Some(SettlementMode::UniformPrice);
+        })
+        .unwrap();
+    let creator = suite.creator.clone();
+    let bidder = suite.bidder.clone();
+
+    suite.set_time(pay_as_bid_start);
+    suite.bid_as(&bidder, 1, &[coin(1_000, IN_DENOM)]).unwrap();
+    suite.bid_as(&bidder, 2, &[coin(1_000, IN_DENOM)]).unwrap();
+    assert_eq!(
+        suite.balance(&bidder, IN_DENOM),
+        Uint128::new(1_000_000 - 2_000)
+    );
+
+    let err = suite.claim_refund(&bidder, 1).unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::AuctionNotFailed {}
+    ));
+
+    // The creator gets the whole offered asset of both auctions back
+    suite.set_time(pay_as_bid_start.plus_seconds(1_001));
+    suite.finalize_auction(&bidder, 1).unwrap();
+    suite.finalize_auction(&bidder, 2).unwrap();
+    assert_eq!(suite.query_auction(1).status, AuctionStatus::Failed);
+    assert_eq!(suite.query_auction(2).status, AuctionStatus::Failed);
+    assert_eq!(
+        suite.balance(&creator, OFFERED_DENOM),
+        Uint128::new(1_000_000)
+    );
+
+    let err = suite.claim_tokens(&bidder, 1).unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::NoTokensToClaim {}
+    ));
+    let err = suite.claim_settlement(&bidder, 2).unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::AuctionNotSettled {}
+    ));
+    let err = suite.claim_proceeds(&creator, 1).unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::NoProceedsToClaim {}
+    ));
+
+    suite.claim_refund(&bidder, 1).unwrap();
+    suite.claim_refund(&bidder, 2).unwrap();
+    assert_eq!(suite.balance(&bidder, IN_DENOM), Uint128::new(1_000_000));
+    let err = suite.claim_refund(&bidder, 1).unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::NoRefundToClaim {}
+    ));
+}
+
+#[test]
+fn test_deferred_token_delivery() {
+    let mut suite = Suite::new();
+    let err = suite
+        .create_auction_with(|options| {
+            options.token_delivery = Some(TokenDelivery::Deferred {
+                claim_start: Some(options.start_time.plus_seconds(999)),
+            });
+        })
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidClaimStart {}
+    ));
+    let err = suite
+        .create_auction_with(|options| {
+            options.token_delivery = Some(TokenDelivery::Deferred {
+                claim_start: Some(options.end_time.plus_seconds(5_001)),
+            });
+        })
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::VestingTooLong {
+            duration: 5_001,
+            max_duration: 5_000
+        }
+    ));
+
+    let start_time = suite
+        .create_auction_with(|options| {
+            options.token_delivery = Some(TokenDelivery::Deferred {
+                claim_start: Some(options.end_time.plus_seconds(100)),
+            });
+        })
+        .unwrap();
+    let bidder = suite.bidder.clone();
+
+    suite.set_time(start_time);
+    suite.bid(1, &[coin(1_000, IN_DENOM)]).unwrap();
+    assert_eq!(suite.balance(&bidder, OFFERED_DENOM), Uint128::zero());
+
+    // Tokens stay locked past the auction end until the claim start
+    suite.set_time(start_time.plus_seconds(1_050));
+    let err = suite.claim_tokens(&bidder, 1).unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::NoTokensToClaim {}
+    ));
+    assert_eq!(
+        suite.claimable(1, &bidder),
+        ClaimableResponse {
+            total_amount: Uint128::new(100),
+            unlocked_amount: Uint128::zero(),
+            claimed_amount: Uint128::zero(),
+            claimable_amount: Uint128::zero(),
+            locked_amount: Uint128::new(100),
+        }
+    );
+
+    suite.set_time(start_time.plus_seconds(1_100));
+    assert_eq!(
+        suite.claimable(1, &bidder).claimable_amount,
+        Uint128::new(100)
+    );
+    suite.claim_tokens(&bidder, 1).unwrap();
+    assert_eq!(suite.balance(&bidder, OFFERED_DENOM), Uint128::new(100));
+    assert_eq!(
+        suite.claimable(1, &bidder).claimable_amount,
+        Uint128::zero()
+    );
+}
+
+#[test]
+fn test_vested_token_delivery() {
+    let mut suite = Suite::new();
+    // Tokens can not be locked past the maximum vesting duration
+    let err = suite
+        .create_auction_with(|options| {
+            options.token_delivery = Some(TokenDelivery::Vesting(VestingSchedule {
+                start_delay: u64::MAX,
+                cliff: 0,
+                duration: 1_000,
+            }));
+        })
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::VestingTooLong { .. }
+    ));
+
+    let start_time = suite
+        .create_auction_with(|options| {
+            options.token_delivery = Some(TokenDelivery::Vesting(VestingSchedule {
+                start_delay: 0,
+                cliff: 100,
+                duration: 1_000,
+            }));
+        })
+        .unwrap();
+    let end_time = start_time.plus_seconds(1_000);
+    let bidder = suite.bidder.clone();
+
+    suite.set_time(start_time);
+    suite.bid(1, &[coin(1_000, IN_DENOM)]).unwrap();
+
+    suite.set_time(end_time.plus_seconds(99));
+    let err = suite.claim_tokens(&bidder, 1).unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::NoTokensToClaim {}
+    ));
+
+    suite.set_time(end_time.plus_seconds(500));
+    suite.claim_tokens(&bidder, 1).unwrap();
+    assert_eq!(suite.balance(&bidder, OFFERED_DENOM), Uint128::new(50));
+
+    suite.set_time(end_time.plus_seconds(750));
+    assert_eq!(
+        suite.claimable(1, &bidder),
+        ClaimableResponse {
+            total_amount: Uint128::new(100),
+            unlocked_amount: Uint128::new(75),
+            claimed_amount: Uint128::new(50),
+            claimable_amount: Uint128::new(25),
+            locked_amount: Uint128::new(25),
+        }
+    );
+
+    suite.set_time(end_time.plus_seconds(2_000));
+    suite.claim_vested(&bidder, 1).unwrap();
+    assert_eq!(suite.balance(&bidder, OFFERED_DENOM), Uint128::new(100));
+}
+
+#[test]
+fn test_proceeds_vesting() {
+    let mut suite = Suite::new();
+    // Proceeds can not be locked past the maximum vesting duration
+    let err = suite
+        .create_auction_with(|options| {
+            options.proceeds_vesting = Some(VestingSchedule {
+                start_delay: 4_001,
+                cliff: 0,
+                duration: 1_000,
+            });
+        })
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::VestingTooLong {
+            duration: 5_001,
+            max_duration: 5_000
+        }
+    ));
+
+    let schedule = VestingSchedule {
+        start_delay: 100,
+        cliff: 0,
+        duration: 1_000,
+    };
+    let start_time = suite
+        .create_auction_with(|options| {
+            options.proceeds_vesting = Some(schedule.clone());
+        })
+        .unwrap();
+    let end_time = start_time.plus_seconds(1_000);
+    let creator = suite.creator.clone();
+
+    suite.set_time(start_time);
+    suite.bid(1, &[coin(1_000, IN_DENOM)]).unwrap();
+
+    // Nothing is released before the vesting start
+    suite.set_time(end_time.plus_seconds(100));
+    let err = suite.claim_proceeds(&creator, 1).unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::NoProceedsToClaim {}
+    ));
+
+    suite.set_time(end_time.plus_seconds(400));
+    suite.claim_proceeds(&creator, 1).unwrap();
+    assert_eq!(suite.balance(&creator, IN_DENOM), Uint128::new(300));
+
+    suite.set_time(end_time.plus_seconds(600));
+    let proceeds: ProceedsResponse = suite
+        .app
+        .wrap()
+        .query_wasm_smart(&suite.contract, &QueryMsg::Proceeds { auction_id: 1 })
+        .unwrap();
+    assert_eq!(
+        proceeds,
+        ProceedsResponse {
+            total_raised: Uint128::new(1_000),
+            vested_amount: Uint128::new(500),
+            claimed_amount: Uint128::new(300),
+            claimable_amount: Uint128::new(200),
+            locked_amount: Uint128::new(500),
+            vesting: Some(schedule),
+            quote_proceeds: vec![],
+        }
+    );
+
+    suite.set_time(end_time.plus_seconds(1_100));
+    suite.claim_proceeds(&creator, 1).unwrap();
+    assert_eq!(suite.balance(&creator, IN_DENOM), Uint128::new(1_000));
+}
+
+#[test]
+fn test_cw20_offered_asset() {
+    let mut suite = Suite::new();
+    let creator = suite.creator.clone();
+    let bidder = suite.bidder.clone();
+    let token = suite.instantiate_cw20(&creator, 10_000);
+
+    // The fee can not travel with a CW20 transfer, it is deposited first
+    let err = suite.create_cw20_auction(&token, 1_000).unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::CreationFeeNotDeposited {}
+    ));
+    let deposit = ExecuteMsg::DepositCreationFee {};
+    suite
+        .app
+        .execute_contract(
+            creator.clone(),
+            suite.contract.clone(),
+            &deposit,
+            &[coin(100, FEE_DENOM)],
+        )
+        .unwrap();
+    let err = suite
+        .app
+        .execute_contract(
+            creator.clone(),
+            suite.contract.clone(),
+            &deposit,
+            &[coin(100, FEE_DENOM)],
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::CreationFeeAlreadyDeposited {}
+    ));
+
+    let start_time = suite.create_cw20_auction(&token, 1_000).unwrap();
+    let auction = suite.query_auction(1);
+    assert_eq!(
+        auction.offered_asset.info,
+        AssetInfo::Cw20 {
+            contract_addr: token.clone()
+        }
+    );
+    assert_eq!(auction.remaining_amount, Uint128::new(1_000));
+    assert_eq!(suite.balance(&suite.admin, FEE_DENOM), Uint128::new(100));
+
+    // The deposit was used up
+    let err = suite
+        .app
+        .execute_contract(
+            creator.clone(),
+            suite.contract.clone(),
+            &ExecuteMsg::WithdrawCreationFee {},
+            &[],
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::CreationFeeNotDeposited {}
+    ));
+
+    suite.set_time(start_time);
+    suite.bid(1, &[coin(1_000, IN_DENOM)]).unwrap();
+    assert_eq!(suite.cw20_balance(&token, &bidder), Uint128::new(100));
+
+    suite.set_time(start_time.plus_seconds(1_001));
+    suite.finalize_auction(&bidder, 1).unwrap();
+    assert_eq!(suite.cw20_balance(&token, &creator), Uint128::new(9_900));
+}
+
+#[test]
+fn test_cancel_cw20_auction() {
+    let mut suite = Suite::new();
+    let creator = suite.creator.clone();
+    let token = suite.instantiate_cw20(&creator, 10_000);
+
+    // An unused deposit can be taken back
+    suite
+        .app
+        .execute_contract(
+            creator.clone(),
+            suite.contract.clone(),
+            &ExecuteMsg::DepositCreationFee {},
+            &[coin(100, FEE_DENOM)],
+        )
+        .unwrap();
+    suite
+        .app
+        .execute_contract(
+            creator.clone(),
+            suite.contract.clone(),
+            &ExecuteMsg::WithdrawCreationFee {},
+            &[],
+        )
+        .unwrap();
+    assert_eq!(suite.balance(&creator, FEE_DENOM), Uint128::new(1_000));
+
+    suite
+        .app
+        .execute_contract(
+            creator.clone(),
+            suite.contract.clone(),
+            &ExecuteMsg::DepositCreationFee {},
+            &[coin(100, FEE_DENOM)],
+        )
+        .unwrap();
+    suite.create_cw20_auction(&token, 1_000).unwrap();
+    assert_eq!(suite.cw20_balance(&token, &creator), Uint128::new(9_000));
+    suite.cancel_auction(1).unwrap();
+    assert_eq!(suite.cw20_balance(&token, &creator), Uint128::new(10_000));
+}
+
+#[test]
+fn test_cw20_bidding_currency() {
+    let mut suite = Suite::new();
+    let admin = suite.admin.clone();
+    let creator = suite.creator.clone();
+    let bidder = suite.bidder.clone();
+    let stable = suite.instantiate_cw20(&bidder, 10_000);
+    suite
+        .app
+        .execute_contract(
+            admin,
+            suite.contract.clone(),
+            &ExecuteMsg::ChangeParams {
+                auction_creation_fee: None,
+                min_seconds_until_auction_start: None,
+                max_auction_duration: None,
+                accepted_denoms: Some(vec![AcceptedDenom {
+                    denom: stable.to_string(),
+                    min_starting_price: None,
+                    min_bid_size: None,
+                }]),
+                cancellation_fee_refund: None,
+                max_vesting_duration: None,
+                admin: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+    let start_time = suite
+        .create_auction_with(|options| {
+            options.in_denom = stable.to_string();
+        })
+        .unwrap();
+    assert_eq!(
+        suite.query_auction(1).in_asset,
+        AssetInfo::Cw20 {
+            contract_addr: stable.clone()
+        }
+    );
+
+    suite.set_time(start_time);
+    let err = suite.bid(1, &[coin(1_000, IN_DENOM)]).unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::Payment(PaymentError::MissingDenom(denom)) if denom == stable.as_str()
+    ));
+
+    // 1055 at price 10 buys 105 tokens, the 5 left over are refunded
+    suite
+        .app
+        .execute_contract(
+            bidder.clone(),
+            stable.clone(),
+            &Cw20ExecuteMsg::Send {
+                contract: suite.contract.to_string(),
+                amount: Uint128::new(1_055),
+                msg: to_json_binary(&Cw20HookMsg::Bid {
+                    auction_id: 1,
+                    max_price: None,
+                    min_amount_out: None,
+                    merkle_proof: None,
+                })
+                .unwrap(),
+            },
+            &[],
+        )
+        .unwrap();
+    assert_eq!(suite.balance(&bidder, OFFERED_DENOM), Uint128::new(105));
+    assert_eq!(
+        suite.cw20_balance(&stable, &bidder),
+        Uint128::new(10_000 - 1_050)
+    );
+
+    suite.claim_proceeds(&creator, 1).unwrap();
+    assert_eq!(suite.cw20_balance(&stable, &creator), Uint128::new(1_050));
+}
+
+#[test]
+fn test_quote_denoms() {
+    const QUOTE_DENOM: &str = "uatom";
+    let mut suite = Suite::new();
+    let admin = suite.admin.clone();
+    let creator = suite.creator.clone();
+    let bidder = suite.bidder.clone();
+    let other_bidder = suite.other_bidder.clone();
+    suite.app.init_modules(|router, _, storage| {
+        router
+            .bank
+            .init_balance(storage, &bidder, vec![coin(10_000, QUOTE_DENOM)])
+            .unwrap();
+    });
+    let accepted_denoms = [IN_DENOM, QUOTE_DENOM]
+        .map(|denom| AcceptedDenom {
+            denom: denom.to_string(),
+            min_starting_price: None,
+            min_bid_size: None,
+        })
+        .to_vec();
+    suite
+        .app
+        .execute_contract(
+            admin,
+            suite.contract.clone(),
+            &ExecuteMsg::ChangeParams {
+                auction_creation_fee: None,
+                min_seconds_until_auction_start: None,
+                max_auction_duration: None,
+                accepted_denoms: Some(accepted_denoms),
+                cancellation_fee_refund: None,
+                max_vesting_duration: None,
+                admin: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+    // One uatom is worth two uusd
+    let quote = |denom: &str| QuoteDenom {
+        denom: denom.to_string(),
+        rate: Decimal::from_str("2").unwrap(),
+    };
+    for denom in [IN_DENOM, "uunknown"] {
+        let err = suite
+            .create_auction_with(|options| {
+                options.quote_denoms = Some(vec![quote(denom)]);
+            })
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast::<ContractError>().unwrap(),
+            ContractError::InvalidQuoteDenom { denom: invalid } if invalid == denom
+        ));
+    }
+    let err = suite
+        .create_auction_with(|options| {
+            options.quote_denoms = Some(vec![quote(QUOTE_DENOM)]);
+            options.settlement_mode = Some(SettlementMode::UniformPrice);
+        })
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::QuoteDenomsRequirePayAsBid {}
+    ));
+
+    let start_time = suite
+        .create_auction_with(|options| {
+            options.quote_denoms = Some(vec![quote(QUOTE_DENOM)]);
+        })
+        .unwrap();
+    suite.set_time(start_time);
+
+    // 533 uatom are worth 1066 uusd and buy 106 tokens at price 10, 530 uatom
+    // are kept and 3 refunded
+    let simulated = suite.simulate_bid(1, coin(533, QUOTE_DENOM), None);
+    assert_eq!(simulated.acquired_amount, Uint128::new(106));
+    assert_eq!(simulated.cost, Uint128::new(530));
+    assert_eq!(simulated.refund_amount, Uint128::new(3));
+
+    suite.bid(1, &[coin(533, QUOTE_DENOM)]).unwrap();
+    assert_eq!(suite.balance(&bidder, OFFERED_DENOM), Uint128::new(106));
+    assert_eq!(
+        suite.balance(&bidder, QUOTE_DENOM),
+        Uint128::new(10_000 - 530)
+    );
+    // Payments are recorded in uusd terms
+    assert_eq!(
+        suite.bidder_position(1, &bidder).total_paid,
+        Uint128::new(1_060)
+    );
+    suite
+        .bid_as(&other_bidder, 1, &[coin(1_000, IN_DENOM)])
+        .unwrap();
+
+    let proceeds: ProceedsResponse = suite
+        .app
+        .wrap()
+        .query_wasm_smart(&suite.contract, &QueryMsg::Proceeds { auction_id: 1 })
+        .unwrap();
+    assert_eq!(proceeds.total_raised, Uint128::new(1_000));
+    assert_eq!(
+        proceeds.quote_proceeds,
+        vec![DenomProceeds {
+            denom: QUOTE_DENOM.to_string(),
+            total_raised: Uint128::new(530),
+            claimed_amount: Uint128::zero(),
+            claimable_amount: Uint128::new(530),
+            locked_amount: Uint128::zero(),
+        }]
+    );
+
+    suite.claim_proceeds(&creator, 1).unwrap();
+    assert_eq!(suite.balance(&creator, IN_DENOM), Uint128::new(1_000));
+    assert_eq!(suite.balance(&creator, QUOTE_DENOM), Uint128::new(530));
+    let err = suite.claim_proceeds(&creator, 1).unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::NoProceedsToClaim {}
+    ));
+}
+
+/// Stand-in for the chain's token factory, denoms are created with their
+/// creator as admin who alone may mint them or hand them over.
+#[cfg(feature = "tokenfactory")]
+mod token_factory_stub {
+    use anyhow::{bail, ensure};
+    use cosmwasm_std::{
+        coins, Addr, Api, Binary, BlockInfo, CustomMsg, CustomQuery, Empty, Querier, Storage,
+    };
+    use cw_multi_test::{AppResponse, BankSudo, CosmosRouter, Module};
+    use cw_storage_plus::Map;
+    use serde::de::DeserializeOwned;
+
+    use crate::token_factory::{full_denom, TokenFactoryMsg};
+
+    pub const DENOM_ADMINS: Map<&str, Addr> = Map::new("tokenfactory_denom_admins");
+
+    pub struct TokenFactoryModule;
+
+    fn ensure_admin(storage: &dyn Storage, denom: &str, sender: &Addr) -> anyhow::Result<()> {
+        let admin = DENOM_ADMINS.may_load(storage, denom)?;
+        ensure!(
+            admin.as_ref() == Some(sender),
+            "{sender} is not the admin of {denom}"
+        );
+        Ok(())
+    }
+
+    impl Module for TokenFactoryModule {
+        type ExecT = TokenFactoryMsg;
+        type QueryT = Empty;
+        type SudoT = Empty;
+
+        fn execute<ExecC, QueryC>(
+            &self,
+            api: &dyn Api,
+            storage: &mut dyn Storage,
+            router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+            block: &BlockInfo,
+            sender: Addr,
+            msg: TokenFactoryMsg,
+        ) -> anyhow::Result<AppResponse>
+        where
+            ExecC: CustomMsg + DeserializeOwned + 'static,
+            QueryC: CustomQuery + DeserializeOwned + 'static,
+        {
+            match msg {
+                TokenFactoryMsg::CreateDenom { subdenom } => {
+                    let denom = full_denom(&sender, &subdenom);
+                    ensure!(!DENOM_ADMINS.has(storage, &denom), "{denom} already exists");
+                    DENOM_ADMINS.save(storage, &denom, &sender)?;
+                    Ok(AppResponse::default())
+                }
+                TokenFactoryMsg::MintTokens {
+                    denom,
+                    amount,
+                    mint_to_address,
+                } => {
+                    ensure_admin(storage, &denom, &sender)?;
+                    let mint = BankSudo::Mint {
+                        to_address: mint_to_address,
+                        amount: coins(amount.u128(), denom),
+                    };
+                    router.sudo(api, storage, block, mint.into())
+                }
+                TokenFactoryMsg::ChangeAdmin {
+                    denom,
+                    new_admin_address,
+                } => {
+                    ensure_admin(storage, &denom, &sender)?;
+                    let new_admin = api.addr_validate(&new_admin_address)?;
+                    DENOM_ADMINS.save(storage, &denom, &new_admin)?;
+                    Ok(AppResponse::default())
+                }
+            }
+        }
+
+        fn query(
+            &self,
+            _api: &dyn Api,
+            _storage: &dyn Storage,
+            _querier: &dyn Querier,
+            _block: &BlockInfo,
+            _request: Empty,
+        ) -> anyhow::Result<Binary> {
+            bail!("token factory queries are not supported")
+        }
+
+        fn sudo<ExecC, QueryC>(
+            &self,
+            _api: &dyn Api,
+            _storage: &mut dyn Storage,
+            _router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+            _block: &BlockInfo,
+            _msg: Empty,
+        ) -> anyhow::Result<AppResponse>
+        where
+            ExecC: CustomMsg + DeserializeOwned + 'static,
+            QueryC: CustomQuery + DeserializeOwned + 'static,
+        {
+            bail!("token factory sudo is not supported")
+        }
+    }
+}
+
+#[cfg(feature = "tokenfactory")]
+impl Suite {
+    /// Creates an auction of 1000 tokens of a new token factory denom, priced
+    /// like `create_auction`.
+    fn create_token_auction(
+        &mut self,
+        settlement_mode: SettlementMode,
+    ) -> anyhow::Result<(String, Timestamp)> {
+        let mut options = self.auction_options();
+        options.settlement_mode = Some(settlement_mode);
+        let start_time = options.start_time;
+        self.app.execute_contract(
+            self.creator.clone(),
+            self.contract.clone(),
+            &ExecuteMsg::CreateTokenAuction {
+                subdenom: "launch".to_string(),
+                supply: Uint128::new(1_000),
+                options,
+            },
+            &[coin(100, FEE_DENOM)],
+        )?;
+        let denom = crate::token_factory::full_denom(&self.contract, "launch");
+        Ok((denom, start_time))
+    }
+
+    fn denom_admin(&self, denom: &str) -> Addr {
+        token_factory_stub::DENOM_ADMINS
+            .load(self.app.storage(), denom)
+            .unwrap()
+    }
+}
+
+#[cfg(feature = "tokenfactory")]
+#[test]
+fn test_token_auction_mints_on_bid() {
+    let mut suite = Suite::new();
+    let creator = suite.creator.clone();
+    let bidder = suite.bidder.clone();
+    let contract = suite.contract.clone();
+
+    let (denom, start_time) = suite
+        .create_token_auction(SettlementMode::PayAsBid)
+        .unwrap();
+    assert_eq!(suite.denom_admin(&denom), contract);
+    assert!(suite.query_auction(1).mint_offered);
+    // A denom can only be created once
+    suite
+        .create_token_auction(SettlementMode::PayAsBid)
+        .unwrap_err();
+
+    // Only what is bought gets minted, 1055 at price 10 buys 105 tokens
+    suite.set_time(start_time);
+    suite.bid(1, &[coin(1_055, IN_DENOM)]).unwrap();
+    assert_eq!(suite.balance(&bidder, &denom), Uint128::new(105));
+    assert_eq!(suite.balance(&contract, &denom), Uint128::zero());
+
+    // Unsold tokens are never minted, the creator takes over the denom
+    suite.set_time(start_time.plus_seconds(1_001));
+    suite.finalize_auction(&creator, 1).unwrap();
+    assert_eq!(suite.balance(&creator, &denom), Uint128::zero());
+    assert_eq!(suite.balance(&contract, &denom), Uint128::zero());
+    assert_eq!(suite.denom_admin(&denom), creator);
+}
+
+#[cfg(feature = "tokenfactory")]
+#[test]
+fn test_token_auction_requires_supply() {
+    let mut suite = Suite::new();
+    let options = suite.auction_options();
+    let err = suite
+        .app
+        .execute_contract(
+            suite.creator.clone(),
+            suite.contract.clone(),
+            &ExecuteMsg::CreateTokenAuction {
+                subdenom: "launch".to_string(),
+                supply: Uint128::zero(),
+                options,
+            },
+            &[coin(100, FEE_DENOM)],
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::ZeroOfferedAmount {}
+    ));
+}
+
+#[cfg(feature = "tokenfactory")]
+#[test]
+fn test_token_auction_uniform_price() {
+    let mut suite = Suite::new();
+    let creator = suite.creator.clone();
+    let bidder = suite.bidder.clone();
+    let contract = suite.contract.clone();
+
+    let (denom, start_time) = suite
+        .create_token_auction(SettlementMode::UniformPrice)
+        .unwrap();
+    suite.set_time(start_time);
+    suite.bid(1, &[coin(500, IN_DENOM)]).unwrap();
+    assert_eq!(suite.balance(&contract, &denom), Uint128::zero());
+
+    // The 500 tokens sold at the end price are minted into escrow on finalization
+    suite.set_time(start_time.plus_seconds(1_001));
+    suite.finalize_auction(&creator, 1).unwrap();
+    assert_eq!(suite.balance(&contract, &denom), Uint128::new(500));
+    assert_eq!(suite.denom_admin(&denom), creator);
+
+    suite.claim_settlement(&bidder, 1).unwrap();
+    assert_eq!(suite.balance(&bidder, &denom), Uint128::new(500));
+    assert_eq!(suite.balance(&contract, &denom), Uint128::zero());
+}
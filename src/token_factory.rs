@@ -0,0 +1,37 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, CosmosMsg, CustomMsg, Uint128};
+
+/// Token factory messages of the chain, sent as custom `CosmosMsg`s.
+///
+/// Serialises to the bare `{"create_denom":…}`, `{"mint_tokens":…}` and
+/// `{"change_admin":…}` shape of Neutron's custom bindings (`NeutronMsg` of
+/// neutron-sdk). Chains using the Osmosis-style token-bindings, which wrap
+/// these in `{"token":{…}}` and take denom metadata, are not supported.
+#[cw_serde]
+pub enum TokenFactoryMsg {
+    CreateDenom {
+        subdenom: String,
+    },
+    MintTokens {
+        denom: String,
+        amount: Uint128,
+        mint_to_address: String,
+    },
+    ChangeAdmin {
+        denom: String,
+        new_admin_address: String,
+    },
+}
+
+impl CustomMsg for TokenFactoryMsg {}
+
+impl From<TokenFactoryMsg> for CosmosMsg<TokenFactoryMsg> {
+    fn from(msg: TokenFactoryMsg) -> Self {
+        CosmosMsg::Custom(msg)
+    }
+}
+
+/// Denom the token factory gives `subdenom` when created by `creator`.
+pub fn full_denom(creator: &Addr, subdenom: &str) -> String {
+    format!("factory/{creator}/{subdenom}")
+}
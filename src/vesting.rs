@@ -0,0 +1,105 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Timestamp, Uint128};
+
+use crate::ContractError;
+
+/// Linear unlock of an amount, measured from the end of the auction.
+#[cw_serde]
+pub struct VestingSchedule {
+    /// Seconds after the auction end before vesting starts
+    pub start_delay: u64,
+    /// Seconds after the vesting start before anything unlocks, the amount
+    /// vested by then unlocks at once
+    pub cliff: u64,
+    /// Seconds after the vesting start until everything is unlocked
+    pub duration: u64,
+}
+
+impl VestingSchedule {
+    pub fn validate(&self) -> Result<(), ContractError> {
+        if self.duration == 0 || self.cliff > self.duration {
+            return Err(ContractError::InvalidVestingSchedule {});
+        }
+        Ok(())
+    }
+
+    /// Seconds after the auction end until everything is unlocked.
+    pub fn lock_duration(&self) -> u64 {
+        self.start_delay.saturating_add(self.duration)
+    }
+
+    /// Part of `total` unlocked at `now` for an auction ending at `end_time`.
+    pub fn vested_amount(&self, total: Uint128, end_time: Timestamp, now: Timestamp) -> Uint128 {
+        // Seconds since the vesting start, counted without building timestamps
+        // that could overflow
+        let elapsed = now
+            .seconds()
+            .checked_sub(end_time.seconds())
+            .and_then(|seconds| seconds.checked_sub(self.start_delay));
+        let Some(elapsed) = elapsed.filter(|elapsed| *elapsed >= self.cliff) else {
+            return Uint128::zero();
+        };
+        if elapsed >= self.duration {
+            return total;
+        }
+        total.multiply_ratio(elapsed, self.duration)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vested_amount() {
+        let schedule = VestingSchedule {
+            start_delay: 100,
+            cliff: 200,
+            duration: 1_000,
+        };
+        let end_time = Timestamp::from_seconds(10_000);
+        let total = Uint128::new(5_000);
+        let vested_at =
+            |seconds: u64| schedule.vested_amount(total, end_time, end_time.plus_seconds(seconds));
+
+        assert_eq!(vested_at(0), Uint128::zero());
+        assert_eq!(vested_at(299), Uint128::zero());
+        // Everything vested during the cliff unlocks at once
+        assert_eq!(vested_at(300), Uint128::new(1_000));
+        assert_eq!(vested_at(601), Uint128::new(2_505));
+        assert_eq!(vested_at(1_100), total);
+        assert_eq!(vested_at(5_000), total);
+    }
+
+    #[test]
+    fn test_vested_amount_far_future() {
+        let schedule = VestingSchedule {
+            start_delay: u64::MAX,
+            cliff: u64::MAX,
+            duration: u64::MAX,
+        };
+        let end_time = Timestamp::from_seconds(10_000);
+        let total = Uint128::new(5_000);
+
+        assert_eq!(
+            schedule.vested_amount(total, end_time, end_time.plus_seconds(1_000)),
+            Uint128::zero()
+        );
+        assert_eq!(schedule.lock_duration(), u64::MAX);
+    }
+
+    #[test]
+    fn test_validate_schedule() {
+        for (cliff, duration) in [(0, 0), (101, 100)] {
+            let schedule = VestingSchedule {
+                start_delay: 0,
+                cliff,
+                duration,
+            };
+            assert!(matches!(
+                schedule.validate(),
+                Err(ContractError::InvalidVestingSchedule {})
+            ));
+        }
+    }
+}
@@ -7,41 +7,84 @@
 import { MsgExecuteContractEncodeObject } from "@cosmjs/cosmwasm-stargate";
 import { MsgExecuteContract } from "cosmjs-types/cosmwasm/wasm/v1/tx";
 import { toUtf8 } from "@cosmjs/encoding";
-import { Uint128, InstantiateMsg, Coin, ExecuteMsg, Decimal, Timestamp, Uint64, QueryMsg, Auction, ArrayOfTupleOfUint8AndAuction, Addr, Params } from "./DutchAuctionLaunchpad.types";
+import { Uint128, Decimal, InstantiateMsg, AcceptedDenom, Coin, ExecuteMsg, Timestamp, Uint64, HexBinary, PriceCurve, SettlementMode, TokenDelivery, Binary, VestingSchedule, QuoteDenom, Cw20ReceiveMsg, MerkleProof, QueryMsg, MigrateMsg, Addr, ArrayOfAddr, AssetInfo, AuctionStatus, Auction, Asset, ArrayOfTupleOfUint64AndAuction, BidderPosition, ArrayOfTupleOfAddrAndBidderPosition, ClaimableResponse, AuctionPhase, CurrentPriceResponse, Params, ProceedsResponse, DenomProceeds, SimulateBidResponse } from "./DutchAuctionLaunchpad.types";
 export interface DutchAuctionLaunchpadMsg {
   contractAddress: string;
   sender: string;
   createAuction: ({
+    allowlistOnly,
     endPrice,
     endTime,
     inDenom,
+    maxBidSize,
+    maxPerWallet,
+    merkleRoot,
+    minRaise,
     offeredAsset,
+    priceCurve,
+    proceedsVesting,
+    quoteDenoms,
+    settlementMode,
     startTime,
-    startingPrice
+    startingPrice,
+    stepInterval,
+    tokenDelivery
   }: {
+    allowlistOnly?: boolean;
     endPrice: Decimal;
     endTime: Timestamp;
     inDenom: string;
+    maxBidSize?: Uint128;
+    maxPerWallet?: Uint128;
+    merkleRoot?: HexBinary;
+    minRaise?: Uint128;
     offeredAsset: Coin;
+    priceCurve?: PriceCurve;
+    proceedsVesting?: VestingSchedule;
+    quoteDenoms?: QuoteDenom[];
+    settlementMode?: SettlementMode;
     startTime: Timestamp;
     startingPrice: Decimal;
+    stepInterval?: number;
+    tokenDelivery?: TokenDelivery;
   }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  receive: ({
+    amount,
+    msg,
+    sender
+  }: {
+    amount: Uint128;
+    msg: Binary;
+    sender: string;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  depositCreationFee: (_funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  withdrawCreationFee: (_funds?: Coin[]) => MsgExecuteContractEncodeObject;
   bid: ({
-    auctionId
+    auctionId,
+    maxPrice,
+    merkleProof,
+    minAmountOut
   }: {
     auctionId: number;
+    maxPrice?: Decimal;
+    merkleProof?: MerkleProof;
+    minAmountOut?: Uint128;
   }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
   changeParams: ({
     acceptedDenoms,
     admin,
     auctionCreationFee,
+    cancellationFeeRefund,
     maxAuctionDuration,
+    maxVestingDuration,
     minSecondsUntilAuctionStart
   }: {
-    acceptedDenoms?: string[];
+    acceptedDenoms?: AcceptedDenom[];
     admin?: string;
     auctionCreationFee?: Coin;
+    cancellationFeeRefund?: Decimal;
     maxAuctionDuration?: number;
+    maxVestingDuration?: number;
     minSecondsUntilAuctionStart?: number;
   }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
   cancelAuction: ({
@@ -49,6 +92,50 @@ export interface DutchAuctionLaunchpadMsg {
   }: {
     auctionId: number;
   }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  claimProceeds: ({
+    auctionId
+  }: {
+    auctionId: number;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  finalizeAuction: ({
+    auctionId
+  }: {
+    auctionId: number;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  addToAllowlist: ({
+    addresses,
+    auctionId
+  }: {
+    addresses: string[];
+    auctionId: number;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  removeFromAllowlist: ({
+    addresses,
+    auctionId
+  }: {
+    addresses: string[];
+    auctionId: number;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  claimSettlement: ({
+    auctionId
+  }: {
+    auctionId: number;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  claimTokens: ({
+    auctionId
+  }: {
+    auctionId: number;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  claimVested: ({
+    auctionId
+  }: {
+    auctionId: number;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  claimRefund: ({
+    auctionId
+  }: {
+    auctionId: number;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
 }
 export class DutchAuctionLaunchpadMsgComposer implements DutchAuctionLaunchpadMsg {
   sender: string;
@@ -58,25 +145,58 @@ export class DutchAuctionLaunchpadMsgComposer implements DutchAuctionLaunchpadMs
     this.sender = sender;
     this.contractAddress = contractAddress;
     this.createAuction = this.createAuction.bind(this);
+    this.receive = this.receive.bind(this);
+    this.depositCreationFee = this.depositCreationFee.bind(this);
+    this.withdrawCreationFee = this.withdrawCreationFee.bind(this);
     this.bid = this.bid.bind(this);
     this.changeParams = this.changeParams.bind(this);
     this.cancelAuction = this.cancelAuction.bind(this);
+    this.claimProceeds = this.claimProceeds.bind(this);
+    this.finalizeAuction = this.finalizeAuction.bind(this);
+    this.addToAllowlist = this.addToAllowlist.bind(this);
+    this.removeFromAllowlist = this.removeFromAllowlist.bind(this);
+    this.claimSettlement = this.claimSettlement.bind(this);
+    this.claimTokens = this.claimTokens.bind(this);
+    this.claimVested = this.claimVested.bind(this);
+    this.claimRefund = this.claimRefund.bind(this);
   }
 
   createAuction = ({
+    allowlistOnly,
     endPrice,
     endTime,
     inDenom,
+    maxBidSize,
+    maxPerWallet,
+    merkleRoot,
+    minRaise,
     offeredAsset,
+    priceCurve,
+    proceedsVesting,
+    quoteDenoms,
+    settlementMode,
     startTime,
-    startingPrice
+    startingPrice,
+    stepInterval,
+    tokenDelivery
   }: {
+    allowlistOnly?: boolean;
     endPrice: Decimal;
     endTime: Timestamp;
     inDenom: string;
+    maxBidSize?: Uint128;
+    maxPerWallet?: Uint128;
+    merkleRoot?: HexBinary;
+    minRaise?: Uint128;
     offeredAsset: Coin;
+    priceCurve?: PriceCurve;
+    proceedsVesting?: VestingSchedule;
+    quoteDenoms?: QuoteDenom[];
+    settlementMode?: SettlementMode;
     startTime: Timestamp;
     startingPrice: Decimal;
+    stepInterval?: number;
+    tokenDelivery?: TokenDelivery;
   }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
       typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
@@ -85,22 +205,90 @@ export class DutchAuctionLaunchpadMsgComposer implements DutchAuctionLaunchpadMs
         contract: this.contractAddress,
         msg: toUtf8(JSON.stringify({
           create_auction: {
+            allowlist_only: allowlistOnly,
             end_price: endPrice,
             end_time: endTime,
             in_denom: inDenom,
+            max_bid_size: maxBidSize,
+            max_per_wallet: maxPerWallet,
+            merkle_root: merkleRoot,
+            min_raise: minRaise,
             offered_asset: offeredAsset,
+            price_curve: priceCurve,
+            proceeds_vesting: proceedsVesting,
+            quote_denoms: quoteDenoms,
+            settlement_mode: settlementMode,
             start_time: startTime,
-            starting_price: startingPrice
+            starting_price: startingPrice,
+            step_interval: stepInterval,
+            token_delivery: tokenDelivery
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  receive = ({
+    amount,
+    msg,
+    sender
+  }: {
+    amount: Uint128;
+    msg: Binary;
+    sender: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          receive: {
+            amount,
+            msg,
+            sender
           }
         })),
         funds: _funds
       })
     };
   };
+  depositCreationFee = (_funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          deposit_creation_fee: {}
+        })),
+        funds: _funds
+      })
+    };
+  };
+  withdrawCreationFee = (_funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          withdraw_creation_fee: {}
+        })),
+        funds: _funds
+      })
+    };
+  };
   bid = ({
-    auctionId
+    auctionId,
+    maxPrice,
+    merkleProof,
+    minAmountOut
   }: {
     auctionId: number;
+    maxPrice?: Decimal;
+    merkleProof?: MerkleProof;
+    minAmountOut?: Uint128;
   }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
       typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
@@ -109,7 +297,10 @@ export class DutchAuctionLaunchpadMsgComposer implements DutchAuctionLaunchpadMs
         contract: this.contractAddress,
         msg: toUtf8(JSON.stringify({
           bid: {
-            auction_id: auctionId
+            auction_id: auctionId,
+            max_price: maxPrice,
+            merkle_proof: merkleProof,
+            min_amount_out: minAmountOut
           }
         })),
         funds: _funds
@@ -120,13 +311,17 @@ export class DutchAuctionLaunchpadMsgComposer implements DutchAuctionLaunchpadMs
     acceptedDenoms,
     admin,
     auctionCreationFee,
+    cancellationFeeRefund,
     maxAuctionDuration,
+    maxVestingDuration,
     minSecondsUntilAuctionStart
   }: {
-    acceptedDenoms?: string[];
+    acceptedDenoms?: AcceptedDenom[];
     admin?: string;
     auctionCreationFee?: Coin;
+    cancellationFeeRefund?: Decimal;
     maxAuctionDuration?: number;
+    maxVestingDuration?: number;
     minSecondsUntilAuctionStart?: number;
   }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
@@ -139,7 +334,9 @@ export class DutchAuctionLaunchpadMsgComposer implements DutchAuctionLaunchpadMs
             accepted_denoms: acceptedDenoms,
             admin,
             auction_creation_fee: auctionCreationFee,
+            cancellation_fee_refund: cancellationFeeRefund,
             max_auction_duration: maxAuctionDuration,
+            max_vesting_duration: maxVestingDuration,
             min_seconds_until_auction_start: minSecondsUntilAuctionStart
           }
         })),
@@ -166,4 +363,162 @@ export class DutchAuctionLaunchpadMsgComposer implements DutchAuctionLaunchpadMs
       })
     };
   };
+  claimProceeds = ({
+    auctionId
+  }: {
+    auctionId: number;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          claim_proceeds: {
+            auction_id: auctionId
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  finalizeAuction = ({
+    auctionId
+  }: {
+    auctionId: number;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          finalize_auction: {
+            auction_id: auctionId
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  addToAllowlist = ({
+    addresses,
+    auctionId
+  }: {
+    addresses: string[];
+    auctionId: number;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          add_to_allowlist: {
+            addresses,
+            auction_id: auctionId
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  removeFromAllowlist = ({
+    addresses,
+    auctionId
+  }: {
+    addresses: string[];
+    auctionId: number;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          remove_from_allowlist: {
+            addresses,
+            auction_id: auctionId
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  claimSettlement = ({
+    auctionId
+  }: {
+    auctionId: number;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          claim_settlement: {
+            auction_id: auctionId
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  claimTokens = ({
+    auctionId
+  }: {
+    auctionId: number;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          claim_tokens: {
+            auction_id: auctionId
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  claimVested = ({
+    auctionId
+  }: {
+    auctionId: number;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          claim_vested: {
+            auction_id: auctionId
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  claimRefund = ({
+    auctionId
+  }: {
+    auctionId: number;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          claim_refund: {
+            auction_id: auctionId
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
 }
\ No newline at end of file
@@ -5,12 +5,126 @@
 */
 
 import { UseQueryOptions, useQuery } from "react-query";
-import { Uint128, InstantiateMsg, Coin, ExecuteMsg, Decimal, Timestamp, Uint64, QueryMsg, Auction, ArrayOfTupleOfUint8AndAuction, Addr, Params } from "./DutchAuctionLaunchpad.types";
+import { Uint128, Decimal, InstantiateMsg, AcceptedDenom, Coin, ExecuteMsg, Timestamp, Uint64, HexBinary, PriceCurve, SettlementMode, TokenDelivery, Binary, VestingSchedule, QuoteDenom, Cw20ReceiveMsg, MerkleProof, QueryMsg, MigrateMsg, Addr, ArrayOfAddr, AssetInfo, AuctionStatus, Auction, Asset, ArrayOfTupleOfUint64AndAuction, BidderPosition, ArrayOfTupleOfAddrAndBidderPosition, ClaimableResponse, AuctionPhase, CurrentPriceResponse, Params, ProceedsResponse, DenomProceeds, SimulateBidResponse } from "./DutchAuctionLaunchpad.types";
 import { DutchAuctionLaunchpadQueryClient } from "./DutchAuctionLaunchpad.client";
 export interface DutchAuctionLaunchpadReactQuery<TResponse, TData = TResponse> {
   client: DutchAuctionLaunchpadQueryClient;
   options?: UseQueryOptions<TResponse, Error, TData>;
 }
+export interface DutchAuctionLaunchpadProceedsQuery<TData> extends DutchAuctionLaunchpadReactQuery<ProceedsResponse, TData> {
+  args: {
+    auctionId: number;
+  };
+}
+export function useDutchAuctionLaunchpadProceedsQuery<TData = ProceedsResponse>({
+  client,
+  args,
+  options
+}: DutchAuctionLaunchpadProceedsQuery<TData>) {
+  return useQuery<ProceedsResponse, Error, TData>(["dutchAuctionLaunchpadProceeds", client.contractAddress, JSON.stringify(args)], () => client.proceeds({
+    auctionId: args.auctionId
+  }), options);
+}
+export interface DutchAuctionLaunchpadClaimableQuery<TData> extends DutchAuctionLaunchpadReactQuery<ClaimableResponse, TData> {
+  args: {
+    auctionId: number;
+    bidder: string;
+  };
+}
+export function useDutchAuctionLaunchpadClaimableQuery<TData = ClaimableResponse>({
+  client,
+  args,
+  options
+}: DutchAuctionLaunchpadClaimableQuery<TData>) {
+  return useQuery<ClaimableResponse, Error, TData>(["dutchAuctionLaunchpadClaimable", client.contractAddress, JSON.stringify(args)], () => client.claimable({
+    auctionId: args.auctionId,
+    bidder: args.bidder
+  }), options);
+}
+export interface DutchAuctionLaunchpadSimulateBidQuery<TData> extends DutchAuctionLaunchpadReactQuery<SimulateBidResponse, TData> {
+  args: {
+    amount: Coin;
+    atTime?: Timestamp;
+    auctionId: number;
+  };
+}
+export function useDutchAuctionLaunchpadSimulateBidQuery<TData = SimulateBidResponse>({
+  client,
+  args,
+  options
+}: DutchAuctionLaunchpadSimulateBidQuery<TData>) {
+  return useQuery<SimulateBidResponse, Error, TData>(["dutchAuctionLaunchpadSimulateBid", client.contractAddress, JSON.stringify(args)], () => client.simulateBid({
+    amount: args.amount,
+    atTime: args.atTime,
+    auctionId: args.auctionId
+  }), options);
+}
+export interface DutchAuctionLaunchpadAllowlistQuery<TData> extends DutchAuctionLaunchpadReactQuery<ArrayOfAddr, TData> {
+  args: {
+    auctionId: number;
+    limit?: number;
+    startAfter?: string;
+  };
+}
+export function useDutchAuctionLaunchpadAllowlistQuery<TData = ArrayOfAddr>({
+  client,
+  args,
+  options
+}: DutchAuctionLaunchpadAllowlistQuery<TData>) {
+  return useQuery<ArrayOfAddr, Error, TData>(["dutchAuctionLaunchpadAllowlist", client.contractAddress, JSON.stringify(args)], () => client.allowlist({
+    auctionId: args.auctionId,
+    limit: args.limit,
+    startAfter: args.startAfter
+  }), options);
+}
+export interface DutchAuctionLaunchpadBidderPositionQuery<TData> extends DutchAuctionLaunchpadReactQuery<BidderPosition, TData> {
+  args: {
+    auctionId: number;
+    bidder: string;
+  };
+}
+export function useDutchAuctionLaunchpadBidderPositionQuery<TData = BidderPosition>({
+  client,
+  args,
+  options
+}: DutchAuctionLaunchpadBidderPositionQuery<TData>) {
+  return useQuery<BidderPosition, Error, TData>(["dutchAuctionLaunchpadBidderPosition", client.contractAddress, JSON.stringify(args)], () => client.bidderPosition({
+    auctionId: args.auctionId,
+    bidder: args.bidder
+  }), options);
+}
+export interface DutchAuctionLaunchpadBidsQuery<TData> extends DutchAuctionLaunchpadReactQuery<ArrayOfTupleOfAddrAndBidderPosition, TData> {
+  args: {
+    auctionId: number;
+    limit?: number;
+    startAfter?: string;
+  };
+}
+export function useDutchAuctionLaunchpadBidsQuery<TData = ArrayOfTupleOfAddrAndBidderPosition>({
+  client,
+  args,
+  options
+}: DutchAuctionLaunchpadBidsQuery<TData>) {
+  return useQuery<ArrayOfTupleOfAddrAndBidderPosition, Error, TData>(["dutchAuctionLaunchpadBids", client.contractAddress, JSON.stringify(args)], () => client.bids({
+    auctionId: args.auctionId,
+    limit: args.limit,
+    startAfter: args.startAfter
+  }), options);
+}
+export interface DutchAuctionLaunchpadCurrentPriceQuery<TData> extends DutchAuctionLaunchpadReactQuery<CurrentPriceResponse, TData> {
+  args: {
+    auctionId: number;
+  };
+}
+export function useDutchAuctionLaunchpadCurrentPriceQuery<TData = CurrentPriceResponse>({
+  client,
+  args,
+  options
+}: DutchAuctionLaunchpadCurrentPriceQuery<TData>) {
+  return useQuery<CurrentPriceResponse, Error, TData>(["dutchAuctionLaunchpadCurrentPrice", client.contractAddress, JSON.stringify(args)], () => client.currentPrice({
+    auctionId: args.auctionId
+  }), options);
+}
 export interface DutchAuctionLaunchpadParamsQuery<TData> extends DutchAuctionLaunchpadReactQuery<Params, TData> {}
 export function useDutchAuctionLaunchpadParamsQuery<TData = Params>({
   client,
@@ -32,18 +146,18 @@ export function useDutchAuctionLaunchpadAuctionQuery<TData = Auction>({
     auctionId: args.auctionId
   }), options);
 }
-export interface DutchAuctionLaunchpadAuctionsQuery<TData> extends DutchAuctionLaunchpadReactQuery<ArrayOfTupleOfUint8AndAuction, TData> {
+export interface DutchAuctionLaunchpadAuctionsQuery<TData> extends DutchAuctionLaunchpadReactQuery<ArrayOfTupleOfUint64AndAuction, TData> {
   args: {
     limit?: number;
     startAfter?: number;
   };
 }
-export function useDutchAuctionLaunchpadAuctionsQuery<TData = ArrayOfTupleOfUint8AndAuction>({
+export function useDutchAuctionLaunchpadAuctionsQuery<TData = ArrayOfTupleOfUint64AndAuction>({
   client,
   args,
   options
 }: DutchAuctionLaunchpadAuctionsQuery<TData>) {
-  return useQuery<ArrayOfTupleOfUint8AndAuction, Error, TData>(["dutchAuctionLaunchpadAuctions", client.contractAddress, JSON.stringify(args)], () => client.auctions({
+  return useQuery<ArrayOfTupleOfUint64AndAuction, Error, TData>(["dutchAuctionLaunchpadAuctions", client.contractAddress, JSON.stringify(args)], () => client.auctions({
     limit: args.limit,
     startAfter: args.startAfter
   }), options);
@@ -5,46 +5,151 @@
 */
 
 export type Uint128 = string;
+export type Decimal = string;
 export interface InstantiateMsg {
-  accepted_denoms: string[];
+  accepted_denoms: AcceptedDenom[];
   admin: string;
   auction_creation_fee: Coin;
+  cancellation_fee_refund?: Decimal | null;
   max_auction_duration: number;
+  max_vesting_duration?: number | null;
   min_seconds_until_auction_start: number;
 }
+export interface AcceptedDenom {
+  denom: string;
+  min_bid_size?: Uint128 | null;
+  min_starting_price?: Decimal | null;
+}
 export interface Coin {
   amount: Uint128;
   denom: string;
 }
 export type ExecuteMsg = {
   create_auction: {
+    allowlist_only?: boolean | null;
     end_price: Decimal;
     end_time: Timestamp;
     in_denom: string;
+    max_bid_size?: Uint128 | null;
+    max_per_wallet?: Uint128 | null;
+    merkle_root?: HexBinary | null;
+    min_raise?: Uint128 | null;
     offered_asset: Coin;
+    price_curve?: PriceCurve | null;
+    proceeds_vesting?: VestingSchedule | null;
+    quote_denoms?: QuoteDenom[] | null;
+    settlement_mode?: SettlementMode | null;
     start_time: Timestamp;
     starting_price: Decimal;
+    step_interval?: number | null;
+    token_delivery?: TokenDelivery | null;
   };
+} | {
+  receive: Cw20ReceiveMsg;
+} | {
+  deposit_creation_fee: {};
+} | {
+  withdraw_creation_fee: {};
 } | {
   bid: {
     auction_id: number;
+    max_price?: Decimal | null;
+    merkle_proof?: MerkleProof | null;
+    min_amount_out?: Uint128 | null;
   };
 } | {
   change_params: {
-    accepted_denoms?: string[] | null;
+    accepted_denoms?: AcceptedDenom[] | null;
     admin?: string | null;
     auction_creation_fee?: Coin | null;
+    cancellation_fee_refund?: Decimal | null;
     max_auction_duration?: number | null;
+    max_vesting_duration?: number | null;
     min_seconds_until_auction_start?: number | null;
   };
 } | {
   cancel_auction: {
     auction_id: number;
   };
+} | {
+  claim_proceeds: {
+    auction_id: number;
+  };
+} | {
+  finalize_auction: {
+    auction_id: number;
+  };
+} | {
+  add_to_allowlist: {
+    addresses: string[];
+    auction_id: number;
+  };
+} | {
+  remove_from_allowlist: {
+    addresses: string[];
+    auction_id: number;
+  };
+} | {
+  claim_settlement: {
+    auction_id: number;
+  };
+} | {
+  claim_tokens: {
+    auction_id: number;
+  };
+} | {
+  claim_vested: {
+    auction_id: number;
+  };
+} | {
+  claim_refund: {
+    auction_id: number;
+  };
 };
-export type Decimal = string;
 export type Timestamp = Uint64;
 export type Uint64 = string;
+export type HexBinary = string;
+export type PriceCurve = "linear" | {
+  exponential_decay: {
+    half_life: number;
+  };
+} | {
+  stepwise: {
+    decrement: Decimal;
+    step_duration: number;
+  };
+} | {
+  piecewise_linear: {
+    breakpoints: [number, Decimal][];
+  };
+};
+export type SettlementMode = "pay_as_bid" | "uniform_price";
+export type TokenDelivery = "instant" | {
+  deferred: {
+    claim_start?: Timestamp | null;
+  };
+} | {
+  vesting: VestingSchedule;
+};
+export type Binary = string;
+export interface VestingSchedule {
+  cliff: number;
+  duration: number;
+  start_delay: number;
+}
+export interface QuoteDenom {
+  denom: string;
+  rate: Decimal;
+}
+export interface Cw20ReceiveMsg {
+  amount: Uint128;
+  msg: Binary;
+  sender: string;
+}
+export interface MerkleProof {
+  allocation: Uint128;
+  proof: HexBinary[];
+}
 export type QueryMsg = {
   auctions: {
     limit?: number | null;
@@ -56,23 +161,144 @@ export type QueryMsg = {
   };
 } | {
   params: {};
+} | {
+  current_price: {
+    auction_id: number;
+  };
+} | {
+  bids: {
+    auction_id: number;
+    limit?: number | null;
+    start_after?: string | null;
+  };
+} | {
+  bidder_position: {
+    auction_id: number;
+    bidder: string;
+  };
+} | {
+  allowlist: {
+    auction_id: number;
+    limit?: number | null;
+    start_after?: string | null;
+  };
+} | {
+  simulate_bid: {
+    amount: Coin;
+    at_time?: Timestamp | null;
+    auction_id: number;
+  };
+} | {
+  claimable: {
+    auction_id: number;
+    bidder: string;
+  };
+} | {
+  proceeds: {
+    auction_id: number;
+  };
 };
+export interface MigrateMsg {
+  legacy_proceeds_recipient?: string | null;
+}
+export type Addr = string;
+export type ArrayOfAddr = Addr[];
+export type AssetInfo = {
+  native: {
+    denom: string;
+  };
+} | {
+  cw20: {
+    contract_addr: Addr;
+  };
+};
+export type AuctionStatus = ("open" | "sold_out" | "finalized" | "cancelled") | "failed";
 export interface Auction {
+  allowlist_only: boolean;
+  clearing_price?: Decimal | null;
   creator: string;
   end_price: Decimal;
   end_time: Timestamp;
+  held_creation_fee?: Coin | null;
+  in_asset: AssetInfo;
   in_denom: string;
-  offered_asset: Coin;
+  max_bid_size?: Uint128 | null;
+  max_per_wallet?: Uint128 | null;
+  merkle_root?: HexBinary | null;
+  min_raise?: Uint128 | null;
+  mint_offered: boolean;
+  offered_asset: Asset;
+  price_curve: PriceCurve;
+  proceeds_vesting?: VestingSchedule | null;
+  quote_denoms: QuoteDenom[];
   remaining_amount: Uint128;
+  settlement_mode: SettlementMode;
   start_time: Timestamp;
   starting_price: Decimal;
+  status: AuctionStatus;
+  step_interval?: number | null;
+  token_delivery: TokenDelivery;
+  total_claimed: Uint128;
+  total_committed: Uint128;
+  total_raised: Uint128;
+}
+export interface Asset {
+  amount: Uint128;
+  info: AssetInfo;
+}
+export type ArrayOfTupleOfUint64AndAuction = [number, Auction][];
+export interface BidderPosition {
+  bid_count: number;
+  claimed_amount: Uint128;
+  total_acquired: Uint128;
+  total_paid: Uint128;
+}
+export type ArrayOfTupleOfAddrAndBidderPosition = [Addr, BidderPosition][];
+export interface ClaimableResponse {
+  claimable_amount: Uint128;
+  claimed_amount: Uint128;
+  locked_amount: Uint128;
+  total_amount: Uint128;
+  unlocked_amount: Uint128;
+}
+export type AuctionPhase = "pending" | "active" | "ended" | "sold_out";
+export interface CurrentPriceResponse {
+  next_price_change?: Timestamp | null;
+  phase: AuctionPhase;
+  price: Decimal;
+  remaining_amount: Uint128;
+  seconds_until_end: number;
+  seconds_until_start: number;
 }
-export type ArrayOfTupleOfUint8AndAuction = [number, Auction][];
-export type Addr = string;
 export interface Params {
-  accepted_denoms: string[];
+  accepted_denoms: AcceptedDenom[];
   admin: Addr;
   auction_creation_fee: Coin;
+  cancellation_fee_refund: Decimal;
   max_auction_duration: number;
+  max_vesting_duration: number;
   min_seconds_until_auction_start: number;
+}
+export interface ProceedsResponse {
+  claimable_amount: Uint128;
+  claimed_amount: Uint128;
+  locked_amount: Uint128;
+  quote_proceeds: DenomProceeds[];
+  total_raised: Uint128;
+  vested_amount: Uint128;
+  vesting?: VestingSchedule | null;
+}
+export interface DenomProceeds {
+  claimable_amount: Uint128;
+  claimed_amount: Uint128;
+  denom: string;
+  locked_amount: Uint128;
+  total_raised: Uint128;
+}
+export interface SimulateBidResponse {
+  acquired_amount: Uint128;
+  cost: Uint128;
+  error?: string | null;
+  price: Decimal;
+  refund_amount: Uint128;
 }
\ No newline at end of file